@@ -5,12 +5,8 @@ use bigbluebutton::Bigbluebutton;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Read API URL and Secret from Environement Variables
-    let bbb_url = std::env::var("BBB_URL").expect("BBB_URL is not set");
-    let bbb_secret = std::env::var("BBB_SECRET").expect("BBB_SECRET is not set");
-
-    // create new client
-    let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+    // create new client from the BBB_URL and BBB_SECRET environment variables
+    let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
     // create new meeting request
     let mut request = CreateMeetingRequest::new("12");
@@ -5,9 +5,7 @@ use bigbluebutton::Bigbluebutton;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let bbb_url = std::env::var("BBB_URL").expect("BBB_URL is not set");
-    let bbb_secret = std::env::var("BBB_SECRET").expect("BBB_SECRET is not set");
-    let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+    let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
     let request = PublishRecordingsRequest::new("karan", false);
 
@@ -0,0 +1,93 @@
+//! Load balancer speaking the BigBlueButton API.
+//!
+//! Accepts API calls signed with its own secret, re-signs them with the secret of the backend
+//! owning the meeting and forwards them. Meeting to server assignments are persisted in SQLite.
+//!
+//! Configuration is read from the environment:
+//! - `BALANCER_SECRET`: secret clients use to sign their calls
+//! - `BALANCER_FALLBACK_SECRETS`: previous secrets still accepted, separated by `,`
+//! - `BALANCER_SERVERS`: backends as `id,url,secret[,weight]` separated by `;`
+//! - `BALANCER_LISTEN`: listen address (default `0.0.0.0:8090`)
+//! - `BALANCER_DATABASE`: SQLite file (default `bbb-balancer.sqlite`)
+
+mod proxy;
+mod store;
+
+use bigbluebutton::cluster::BigbluebuttonCluster;
+use bigbluebutton::Bigbluebutton;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::Server;
+use proxy::Balancer;
+use std::convert::Infallible;
+use std::env::var;
+use std::sync::Arc;
+use store::AffinityStore;
+
+fn parse_servers(value: &str) -> anyhow::Result<BigbluebuttonCluster> {
+    let mut cluster = BigbluebuttonCluster::new();
+    for entry in value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let fields = entry.split(',').map(str::trim).collect::<Vec<_>>();
+        let (id, url, secret, weight) = match fields.as_slice() {
+            [id, url, secret] => (id, url, secret, 1),
+            [id, url, secret, weight] => (id, url, secret, weight.parse()?),
+            _ => anyhow::bail!(
+                "invalid server entry {:?}, expected id,url,secret[,weight]",
+                entry
+            ),
+        };
+        cluster = cluster.add_server(id, Bigbluebutton::new(url, secret), weight);
+    }
+    if cluster.server_ids().is_empty() {
+        anyhow::bail!("BALANCER_SERVERS does not contain any server");
+    }
+    Ok(cluster)
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let secret =
+        var("BALANCER_SECRET").map_err(|_| anyhow::anyhow!("BALANCER_SECRET is not set"))?;
+    let servers =
+        var("BALANCER_SERVERS").map_err(|_| anyhow::anyhow!("BALANCER_SERVERS is not set"))?;
+    let listen = var("BALANCER_LISTEN").unwrap_or_else(|_| "0.0.0.0:8090".to_string());
+    let database = var("BALANCER_DATABASE").unwrap_or_else(|_| "bbb-balancer.sqlite".to_string());
+
+    let cluster = parse_servers(&servers)?;
+    let store = AffinityStore::open(&database)?;
+    for (meeting_id, server_id) in store.load().await? {
+        cluster.set_affinity(meeting_id, server_id);
+    }
+
+    let mut frontend = Bigbluebutton::builder("http://localhost/bigbluebutton/", secret);
+    for fallback in var("BALANCER_FALLBACK_SECRETS")
+        .unwrap_or_default()
+        .split(',')
+    {
+        if !fallback.trim().is_empty() {
+            frontend = frontend.fallback_secret(fallback.trim());
+        }
+    }
+
+    let balancer = Arc::new(Balancer {
+        frontend: frontend.build(),
+        cluster,
+        store,
+    });
+    let make_service = make_service_fn(move |_| {
+        let balancer = balancer.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |request| balancer.clone().handle(request))) }
+    });
+
+    let server = Server::bind(&listen.parse()?).serve(make_service);
+    eprintln!("bbb-balancer listening on {}", server.local_addr());
+    server
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,372 @@
+use crate::store::AffinityStore;
+use bigbluebutton::cluster::BigbluebuttonCluster;
+use bigbluebutton::error::ClusterError;
+use bigbluebutton::Bigbluebutton;
+use hyper::{header, Body, Request, Response, StatusCode};
+use std::convert::Infallible;
+use std::sync::Arc;
+
+const API_PREFIX: &str = "/bigbluebutton/api";
+
+/// Frontend speaking the BBB API and forwarding calls to the cluster
+pub struct Balancer {
+    /// Client holding the frontend secret, used to verify incoming checksums
+    pub frontend: Bigbluebutton,
+    /// Backend servers
+    pub cluster: BigbluebuttonCluster,
+    /// Persisted meeting affinity
+    pub store: AffinityStore,
+}
+
+type Params = Vec<(String, String)>;
+
+/// FAILED response of a backend, returned to the caller as is
+#[derive(Debug)]
+struct FailedResponse(String);
+
+impl std::fmt::Display for FailedResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "backend answered {}", self.0)
+    }
+}
+
+impl std::error::Error for FailedResponse {}
+
+impl Balancer {
+    pub async fn handle(
+        self: Arc<Self>,
+        request: Request<Body>,
+    ) -> Result<Response<Body>, Infallible> {
+        let (parts, body) = request.into_parts();
+        let path = parts.uri.path().trim_end_matches('/');
+        let api = match path.strip_prefix(API_PREFIX) {
+            Some("") => return Ok(xml(version())),
+            Some(api) => api.trim_start_matches('/').to_string(),
+            None => return Ok(not_found()),
+        };
+        let query = parts.uri.query().unwrap_or("").to_string();
+        if !self.frontend.verify_checksum(&api, &query) {
+            return Ok(xml(failed("checksumError", "Checksums do not match")));
+        }
+        let params: Params = url::form_urlencoded::parse(query.as_bytes())
+            .filter(|(key, _)| key != "checksum")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        let response = match api.as_str() {
+            "create" => {
+                let content_type = parts
+                    .headers
+                    .get(header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok());
+                self.create(params, content_type, body).await
+            }
+            "join" => self.join(params).await,
+            "getMeetings" => Ok(xml(self
+                .aggregate("getMeetings", &params, "meetings", "noMeetings")
+                .await)),
+            "getRecordings" => Ok(xml(self
+                .aggregate("getRecordings", &params, "recordings", "noRecordings")
+                .await)),
+            "publishRecordings" | "deleteRecordings" | "updateRecordings" => {
+                self.forward_recordings(&api, params).await
+            }
+            "end" | "getMeetingInfo" | "isMeetingRunning" | "sendChatMessage" => {
+                self.forward(&api, params).await
+            }
+            _ => Ok(xml(failed(
+                "unsupportedRequest",
+                "This request is not supported by the balancer",
+            ))),
+        };
+        Ok(response.unwrap_or_else(|error| xml(failed("internalError", &error.to_string()))))
+    }
+
+    /// Creates the meeting on its owning server, or the least loaded one, forwarding the POST
+    /// body such as the presentations to pre-upload
+    async fn create(
+        &self,
+        params: Params,
+        content_type: Option<&str>,
+        body: Body,
+    ) -> anyhow::Result<Response<Body>> {
+        let body = String::from_utf8(hyper::body::to_bytes(body).await?.to_vec())?;
+        let content_type = content_type.unwrap_or("application/xml");
+        let meeting_id = param(&params, "meetingID").unwrap_or_default();
+        let known = self.cluster.server_for(&meeting_id).is_some();
+
+        let created = self
+            .cluster
+            .create_with(&meeting_id, |server| {
+                let (params, body) = (pairs(&params), body.as_str());
+                async move {
+                    let response = if body.is_empty() {
+                        server.request_raw("create", params).await?
+                    } else {
+                        server
+                            .request_raw_with_body("create", params, content_type, body)
+                            .await?
+                    };
+                    if is_success(&response) {
+                        Ok(response)
+                    } else {
+                        Err(FailedResponse(response).into())
+                    }
+                }
+            })
+            .await;
+        if known && self.cluster.server_for(&meeting_id).is_none() {
+            self.store.remove(&meeting_id).await?;
+        }
+        match created {
+            Ok((server_id, response)) => {
+                self.store.save(&meeting_id, &server_id).await?;
+                Ok(xml(response))
+            }
+            Err(error) => match error.downcast::<FailedResponse>() {
+                Ok(FailedResponse(response)) => Ok(xml(response)),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
+    /// Redirects to a join URL signed by the owning server
+    async fn join(&self, params: Params) -> anyhow::Result<Response<Body>> {
+        let meeting_id = param(&params, "meetingID").unwrap_or_default();
+        let server_id = match self.cluster.locate_meeting(&meeting_id).await {
+            Ok(server_id) => server_id,
+            Err(_) => return Ok(xml(meeting_not_found())),
+        };
+        if param(&params, "redirect").as_deref() == Some("false") {
+            let body = self
+                .cluster
+                .request_raw(&server_id, "join", pairs(&params))
+                .await?;
+            return Ok(xml(body));
+        }
+        let server = self
+            .cluster
+            .server(&server_id)
+            .ok_or_else(|| ClusterError::UnknownServer(server_id.clone()))?;
+        let location = server.generate_url("join", pairs(&params))?;
+        Ok(Response::builder()
+            .status(StatusCode::FOUND)
+            .header(header::LOCATION, location)
+            .body(Body::empty())?)
+    }
+
+    /// Forwards a meeting scoped call to the server owning the meeting
+    async fn forward(&self, api: &str, params: Params) -> anyhow::Result<Response<Body>> {
+        let meeting_id = param(&params, "meetingID").unwrap_or_default();
+        let known = self.cluster.server_for(&meeting_id).is_some();
+        let server_id =
+            match self.cluster.locate_meeting(&meeting_id).await {
+                Ok(server_id) => server_id,
+                Err(_) if api == "isMeetingRunning" => return Ok(xml(
+                    "<response><returncode>SUCCESS</returncode><running>false</running></response>"
+                        .to_string(),
+                )),
+                Err(_) => return Ok(xml(meeting_not_found())),
+            };
+        if !known {
+            self.store.save(&meeting_id, &server_id).await?;
+        }
+
+        let body = self
+            .cluster
+            .request_raw(&server_id, api, pairs(&params))
+            .await?;
+        if api == "end" && is_success(&body) {
+            self.cluster.remove_affinity(&meeting_id);
+            self.store.remove(&meeting_id).await?;
+        }
+        Ok(xml(body))
+    }
+
+    /// Sends the call with the caller's filters to every server and merges the `<element>`
+    /// lists of the responses
+    async fn aggregate(
+        &self,
+        api: &str,
+        params: &Params,
+        element: &str,
+        empty_key: &str,
+    ) -> String {
+        let mut items = String::new();
+        for server_id in self.cluster.available_server_ids() {
+            if let Ok(body) = self
+                .cluster
+                .request_raw(&server_id, api, pairs(params))
+                .await
+            {
+                if let Some(inner) = inner_xml(&body, element) {
+                    items.push_str(inner);
+                }
+            }
+        }
+        if items.is_empty() {
+            format!(
+                "<response><returncode>SUCCESS</returncode><{0}/><messageKey>{1}</messageKey>\
+                <message>There are no {0} for this request.</message></response>",
+                element, empty_key
+            )
+        } else {
+            format!(
+                "<response><returncode>SUCCESS</returncode><{0}>{1}</{0}></response>",
+                element, items
+            )
+        }
+    }
+
+    /// Forwards a recording call to the servers owning the recordings, each receiving only the
+    /// `recordID`s it owns.
+    ///
+    /// The first FAILED response is returned, otherwise the last successful one.
+    async fn forward_recordings(
+        &self,
+        api: &str,
+        params: Params,
+    ) -> anyhow::Result<Response<Body>> {
+        let record_ids = param(&params, "recordID").unwrap_or_default();
+        let owners = self.recording_owners(&record_ids).await?;
+        if owners.is_empty() {
+            return Ok(xml(failed("notFound", "We could not find recordings")));
+        }
+
+        let mut last = String::new();
+        for (server_id, owned) in owners {
+            let params = params
+                .iter()
+                .map(|(key, value)| match key.as_str() {
+                    "recordID" => (key.clone(), owned.join(",")),
+                    _ => (key.clone(), value.clone()),
+                })
+                .collect::<Params>();
+            let body = self
+                .cluster
+                .request_raw(&server_id, api, pairs(&params))
+                .await?;
+            if !is_success(&body) {
+                return Ok(xml(body));
+            }
+            last = body;
+        }
+        Ok(xml(last))
+    }
+
+    /// Asks every server which of the comma separated `recordID`s it holds
+    async fn recording_owners(
+        &self,
+        record_ids: &str,
+    ) -> anyhow::Result<Vec<(String, Vec<String>)>> {
+        let mut owners = Vec::new();
+        let mut last_error = None;
+        for server_id in self.cluster.available_server_ids() {
+            let body = match self
+                .cluster
+                .request_raw(&server_id, "getRecordings", [("recordID", record_ids)])
+                .await
+            {
+                Ok(body) => body,
+                Err(error) => {
+                    last_error = Some(error);
+                    continue;
+                }
+            };
+            let owned = inner_xml(&body, "recordings")
+                .map(|recordings| all_inner_xml(recordings, "recordID"))
+                .unwrap_or_default()
+                .into_iter()
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            if !owned.is_empty() {
+                owners.push((server_id, owned));
+            }
+        }
+        match last_error {
+            // a server which did not answer may own the recordings
+            Some(error) if owners.is_empty() => Err(error),
+            _ => Ok(owners),
+        }
+    }
+}
+
+fn pairs(params: &Params) -> impl Iterator<Item = (&str, &str)> {
+    params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+}
+
+fn param(params: &Params, key: &str) -> Option<String> {
+    params
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.clone())
+}
+
+fn is_success(body: &str) -> bool {
+    body.contains("<returncode>SUCCESS</returncode>")
+}
+
+/// Returns the content between `<element>` and `</element>`
+fn inner_xml<'a>(body: &'a str, element: &str) -> Option<&'a str> {
+    let open = format!("<{}>", element);
+    let close = format!("</{}>", element);
+    let start = body.find(&open)? + open.len();
+    let end = body.rfind(&close)?;
+    body.get(start..end)
+}
+
+/// Returns the content of every `<element>` of the body
+fn all_inner_xml<'a>(body: &'a str, element: &str) -> Vec<&'a str> {
+    let open = format!("<{}>", element);
+    let close = format!("</{}>", element);
+    let mut values = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        let end = match rest.find(&close) {
+            Some(end) => end,
+            None => break,
+        };
+        values.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    values
+}
+
+fn version() -> String {
+    "<response><returncode>SUCCESS</returncode><version>2.0</version></response>".to_string()
+}
+
+fn failed(message_key: &str, message: &str) -> String {
+    let message = message
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!(
+        "<response><returncode>FAILED</returncode><messageKey>{}</messageKey><message>{}</message></response>",
+        message_key, message
+    )
+}
+
+fn meeting_not_found() -> String {
+    failed(
+        "notFound",
+        "We could not find a meeting with that meeting ID - perhaps the meeting is not yet running?",
+    )
+}
+
+fn xml(body: String) -> Response<Body> {
+    Response::builder()
+        .header(header::CONTENT_TYPE, "text/xml")
+        .body(Body::from(body))
+        .expect("static response is valid")
+}
+
+fn not_found() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Body::empty())
+        .expect("static response is valid")
+}
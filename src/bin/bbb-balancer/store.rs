@@ -0,0 +1,79 @@
+use rusqlite::{params, Connection};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Meeting to server assignments persisted in a SQLite file.
+///
+/// Queries run on the blocking thread pool so that they do not stall the request handlers.
+pub struct AffinityStore {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl AffinityStore {
+    /// Opens the database, creating the schema when missing
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS meetings (
+                meeting_id TEXT PRIMARY KEY NOT NULL,
+                server_id TEXT NOT NULL,
+                updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            )",
+        )?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Every persisted assignment
+    pub async fn load(&self) -> anyhow::Result<HashMap<String, String>> {
+        self.run(|connection| {
+            let mut statement = connection.prepare("SELECT meeting_id, server_id FROM meetings")?;
+            let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect()
+        })
+        .await
+    }
+
+    /// Records that a meeting lives on a server
+    pub async fn save(&self, meeting_id: &str, server_id: &str) -> anyhow::Result<()> {
+        let (meeting_id, server_id) = (meeting_id.to_string(), server_id.to_string());
+        self.run(move |connection| {
+            connection.execute(
+                "INSERT INTO meetings (meeting_id, server_id) VALUES (?1, ?2)
+                 ON CONFLICT(meeting_id) DO UPDATE SET
+                    server_id = excluded.server_id,
+                    updated_at = strftime('%s', 'now')",
+                params![meeting_id, server_id],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Forgets a meeting
+    pub async fn remove(&self, meeting_id: &str) -> anyhow::Result<()> {
+        let meeting_id = meeting_id.to_string();
+        self.run(move |connection| {
+            connection.execute(
+                "DELETE FROM meetings WHERE meeting_id = ?1",
+                params![meeting_id],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn run<T, F>(&self, query: F) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        let result =
+            tokio::task::spawn_blocking(move || query(&connection.lock().expect("store poisoned")))
+                .await?;
+        Ok(result?)
+    }
+}
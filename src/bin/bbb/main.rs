@@ -0,0 +1,488 @@
+//! Command line client for day-to-day BigBlueButton operations.
+//!
+//! The server is configured with `--config <file>`, or the environment variables read by
+//! [bigbluebutton::config::Config::from_env], optionally overridden by `--url`. The secret is
+//! only read from `BBB_SECRET` or the configuration file, never from the command line where it
+//! would show up in the process list and the shell history.
+
+mod output;
+
+use bigbluebutton::administration::maintenance::{EndOutcome, MaintenancePlan};
+use bigbluebutton::administration::{CreateMeetingRequest, EndMeetingRequest, JoinMeetingRequest};
+use bigbluebutton::config::Config;
+use bigbluebutton::monitoring::{GetMeetingInfoRequest, GetMeetingsRequest};
+use bigbluebutton::recording::{
+    DeleteRecordingsRequest, GetRecordingsRequest, PublishRecordingsRequest,
+    UpdateRecordingsRequest,
+};
+use bigbluebutton::webhook::{CreateHookRequest, DestroyHookRequest, ListHooksRequest};
+use bigbluebutton::{Bigbluebutton, SecretMatch};
+use clap::{Parser, Subcommand};
+use output::{Format, Table};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(name = "bbb", about = "Operate BigBlueButton servers through the API")]
+struct Cli {
+    /// TOML or JSON configuration file
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Server URL, e.g. https://example.com/bigbluebutton/
+    #[arg(long, global = true)]
+    url: Option<String>,
+
+    /// Output format
+    #[arg(long, short, global = true, value_enum, default_value = "table")]
+    output: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Running meetings
+    #[command(subcommand)]
+    Meetings(MeetingsCommand),
+
+    /// A single meeting
+    #[command(subcommand)]
+    Meeting(MeetingCommand),
+
+    /// Prints a join URL
+    JoinUrl {
+        meeting_id: String,
+        full_name: String,
+        /// Meeting password
+        #[arg(long)]
+        password: Option<String>,
+        /// MODERATOR or VIEWER (2.6+)
+        #[arg(long)]
+        role: Option<String>,
+        /// User id
+        #[arg(long)]
+        user_id: Option<String>,
+    },
+
+    /// Webhooks
+    #[command(subcommand)]
+    Hooks(HooksCommand),
+
+    /// Recordings
+    #[command(subcommand)]
+    Recordings(RecordingsCommand),
+
+    /// Checks that the server is reachable and accepts the secret
+    Probe,
+}
+
+#[derive(Debug, Subcommand)]
+enum MeetingsCommand {
+    /// Lists the running meetings
+    List,
+
+    /// Ends every running meeting, e.g. before maintenance
+    EndAll {
+        /// Chat message posted to every meeting first
+        #[arg(long)]
+        warning: Option<String>,
+        /// Seconds to wait between the warning and ending the meetings
+        #[arg(long, default_value_t = 0)]
+        grace_period: u64,
+        /// Meetings ended at the same time
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+        /// Only lists the meetings which would be ended
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum MeetingCommand {
+    /// Shows a meeting and its attendees
+    Info { meeting_id: String },
+
+    /// Creates a meeting
+    Create {
+        meeting_id: String,
+        /// Meeting name
+        #[arg(long)]
+        name: Option<String>,
+        /// Moderator password
+        #[arg(long)]
+        moderator_pw: Option<String>,
+        /// Attendee password
+        #[arg(long)]
+        attendee_pw: Option<String>,
+        /// Records the meeting
+        #[arg(long)]
+        record: bool,
+    },
+
+    /// Ends a meeting
+    End {
+        meeting_id: String,
+        /// Moderator password, looked up with getMeetingInfo when missing
+        #[arg(long)]
+        password: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum HooksCommand {
+    /// Lists the registered hooks
+    List {
+        /// Only hooks receiving events of this meeting
+        #[arg(long)]
+        meeting_id: Option<String>,
+    },
+
+    /// Registers a hook
+    Create {
+        callback_url: String,
+        /// Only send events of this meeting
+        #[arg(long)]
+        meeting_id: Option<String>,
+        /// Send raw events
+        #[arg(long)]
+        raw: bool,
+    },
+
+    /// Removes a hook
+    Destroy { hook_id: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum RecordingsCommand {
+    /// Lists recordings
+    List {
+        /// Only recordings of these meetings, comma separated
+        #[arg(long)]
+        meeting_id: Option<String>,
+        /// Only recordings in these states, comma separated
+        #[arg(long)]
+        state: Option<String>,
+    },
+
+    /// Publishes recordings
+    Publish { record_id: String },
+
+    /// Unpublishes recordings
+    Unpublish { record_id: String },
+
+    /// Deletes recordings
+    Delete { record_id: String },
+
+    /// Updates recording metadata
+    Update {
+        record_id: String,
+        /// Metadata as name=value, an empty value removes it
+        #[arg(long = "meta", value_parser = parse_meta)]
+        meta: Vec<(String, String)>,
+    },
+}
+
+fn parse_meta(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected name=value, got {}", value))
+}
+
+fn client(cli: &Cli) -> anyhow::Result<Bigbluebutton> {
+    let mut config = match &cli.config {
+        Some(path) => Config::from_file(path)?,
+        None => Config::from_env()?,
+    };
+    if let Some(url) = &cli.url {
+        config.url = Some(url.clone());
+    }
+    config.client()
+}
+
+fn single(headers: Vec<&'static str>, row: Vec<String>) -> Table {
+    let mut table = Table::new(headers);
+    table.row(row);
+    table
+}
+
+async fn run(cli: &Cli) -> anyhow::Result<Table> {
+    let client = client(cli)?;
+    let table = match &cli.command {
+        Command::Meetings(MeetingsCommand::List) => {
+            let response = client.get_meetings(&GetMeetingsRequest::new()).await?;
+            let mut table = Table::new(vec![
+                "meeting_id",
+                "name",
+                "running",
+                "participants",
+                "moderators",
+                "created",
+            ]);
+            for meeting in response.meetings() {
+                table.row(vec![
+                    meeting.meeting_id().clone(),
+                    meeting.meeting_name().clone(),
+                    meeting.running().clone(),
+                    meeting.participant_count().clone(),
+                    meeting.moderator_count().clone(),
+                    meeting.create_date().clone(),
+                ]);
+            }
+            table
+        }
+        Command::Meetings(MeetingsCommand::EndAll {
+            warning,
+            grace_period,
+            concurrency,
+            dry_run,
+        }) => {
+            let mut plan = MaintenancePlan::new();
+            plan.set_warning(warning.clone());
+            plan.set_grace_period(Duration::from_secs(*grace_period));
+            plan.set_concurrency(*concurrency);
+            plan.set_dry_run(*dry_run);
+            let report = client.end_all_meetings(&plan).await?;
+            let mut table = Table::new(vec![
+                "meeting_id",
+                "name",
+                "participants",
+                "warned",
+                "outcome",
+            ]);
+            for meeting in report.meetings() {
+                table.row(vec![
+                    meeting.meeting_id().clone(),
+                    meeting.meeting_name().clone(),
+                    meeting.participant_count().clone(),
+                    meeting.warned().to_string(),
+                    match meeting.outcome() {
+                        EndOutcome::Ended => "ended".to_string(),
+                        EndOutcome::WouldEnd => "would end".to_string(),
+                        EndOutcome::Failed(error) => format!("failed: {}", error),
+                    },
+                ]);
+            }
+            table
+        }
+        Command::Meeting(MeetingCommand::Info { meeting_id }) => {
+            let mut request = GetMeetingInfoRequest::new();
+            request.meeting_id = Some(meeting_id.clone());
+            let response = client.get_meeting_info(&request).await?;
+            let mut table = Table::new(vec!["user_id", "full_name", "role", "presenter", "video"]);
+            for attendee in response.attendees() {
+                table.row(vec![
+                    attendee.user_id().clone(),
+                    attendee.full_name().clone(),
+                    attendee.role().clone(),
+                    attendee.is_presenter().clone(),
+                    attendee.has_video().to_string(),
+                ]);
+            }
+            table
+        }
+        Command::Meeting(MeetingCommand::Create {
+            meeting_id,
+            name,
+            moderator_pw,
+            attendee_pw,
+            record,
+        }) => {
+            let mut request = CreateMeetingRequest::new(meeting_id);
+            request.set_name(name.clone());
+            request.set_moderator_pw(moderator_pw.clone());
+            request.set_attendee_pw(attendee_pw.clone());
+            if *record {
+                request.set_record(Some(true));
+            }
+            let response = client.create_meeting(&request).await?;
+            single(
+                vec![
+                    "meeting_id",
+                    "internal_meeting_id",
+                    "moderator_pw",
+                    "attendee_pw",
+                ],
+                vec![
+                    response.meeting_id().clone(),
+                    response.internal_meeting_id().clone(),
+                    response.moderator_pw().clone(),
+                    response.attendee_pw().clone(),
+                ],
+            )
+        }
+        Command::Meeting(MeetingCommand::End {
+            meeting_id,
+            password,
+        }) => {
+            let password = match password {
+                Some(password) => password.clone(),
+                None => {
+                    let mut request = GetMeetingInfoRequest::new();
+                    request.meeting_id = Some(meeting_id.clone());
+                    client
+                        .get_meeting_info(&request)
+                        .await?
+                        .moderator_pw()
+                        .clone()
+                }
+            };
+            let response = client
+                .end_meeting(&EndMeetingRequest::new(meeting_id, password))
+                .await?;
+            single(
+                vec!["meeting_id", "message_key"],
+                vec![meeting_id.clone(), response.message_key().clone()],
+            )
+        }
+        Command::JoinUrl {
+            meeting_id,
+            full_name,
+            password,
+            role,
+            user_id,
+        } => {
+            let mut request = JoinMeetingRequest::new(meeting_id, full_name);
+            request.set_password(password.clone());
+            request.set_role(role.clone());
+            request.set_user_id(user_id.clone());
+            single(vec!["url"], vec![client.join_url(&request)?])
+        }
+        Command::Hooks(HooksCommand::List { meeting_id }) => {
+            let mut request = ListHooksRequest::new();
+            request.meeting_id = meeting_id.clone();
+            let response = client.list_hooks(&request).await?;
+            let mut table = Table::new(vec!["hook_id", "callback_url", "meeting_id", "permanent"]);
+            for hook in response.hooks() {
+                table.row(vec![
+                    hook.hook_id().clone(),
+                    hook.callback_url().clone(),
+                    hook.meeting_id().clone().unwrap_or_default(),
+                    hook.permanent_hook().to_string(),
+                ]);
+            }
+            table
+        }
+        Command::Hooks(HooksCommand::Create {
+            callback_url,
+            meeting_id,
+            raw,
+        }) => {
+            let mut request = CreateHookRequest::new(callback_url);
+            request.meeting_id = meeting_id.clone();
+            if *raw {
+                request.get_raw = Some(true);
+            }
+            let response = client.create_hook(&request).await?;
+            single(vec!["hook_id"], vec![response.hook_id().clone()])
+        }
+        Command::Hooks(HooksCommand::Destroy { hook_id }) => {
+            let response = client
+                .destroy_hook(&DestroyHookRequest::new(hook_id))
+                .await?;
+            single(
+                vec!["hook_id", "removed"],
+                vec![hook_id.clone(), response.removed().to_string()],
+            )
+        }
+        Command::Recordings(RecordingsCommand::List { meeting_id, state }) => {
+            let mut request = GetRecordingsRequest::new();
+            request.meeting_id = meeting_id.clone();
+            request.state = state.clone();
+            let response = client.get_recordings(&request).await?;
+            let mut table = Table::new(vec![
+                "record_id",
+                "meeting_id",
+                "name",
+                "published",
+                "state",
+                "participants",
+                "playback_url",
+            ]);
+            for recording in response.recordings() {
+                table.row(vec![
+                    recording.record_id().clone(),
+                    recording.meeting_id().clone(),
+                    recording.name().clone(),
+                    recording.published().to_string(),
+                    recording.state().clone().unwrap_or_default(),
+                    recording.participants().clone().unwrap_or_default(),
+                    recording.playback_url().unwrap_or_default().to_string(),
+                ]);
+            }
+            table
+        }
+        Command::Recordings(RecordingsCommand::Publish { record_id })
+        | Command::Recordings(RecordingsCommand::Unpublish { record_id }) => {
+            let publish = matches!(
+                cli.command,
+                Command::Recordings(RecordingsCommand::Publish { .. })
+            );
+            let response = client
+                .publish_recordings(&PublishRecordingsRequest::new(record_id, publish))
+                .await?;
+            single(
+                vec!["record_id", "published"],
+                vec![record_id.clone(), response.published().to_string()],
+            )
+        }
+        Command::Recordings(RecordingsCommand::Delete { record_id }) => {
+            let response = client
+                .delete_recordings(&DeleteRecordingsRequest::new(record_id))
+                .await?;
+            single(
+                vec!["record_id", "deleted"],
+                vec![record_id.clone(), response.deleted().to_string()],
+            )
+        }
+        Command::Recordings(RecordingsCommand::Update { record_id, meta }) => {
+            let request = meta.iter().fold(
+                UpdateRecordingsRequest::new(record_id),
+                |request, (name, value)| request.meta(name, value),
+            );
+            let response = client.update_recordings(&request).await?;
+            single(
+                vec!["record_id", "updated"],
+                vec![record_id.clone(), response.updated().to_string()],
+            )
+        }
+        Command::Probe => {
+            let info = client.probe().await?;
+            single(
+                vec![
+                    "version",
+                    "bbb_version",
+                    "checksum_algorithm",
+                    "secret",
+                    "latency_ms",
+                ],
+                vec![
+                    info.version,
+                    info.bbb_version.unwrap_or_default(),
+                    info.checksum_algorithm,
+                    match info.secret {
+                        SecretMatch::Primary => "primary".to_string(),
+                        SecretMatch::Fallback(index) => format!("fallback {}", index),
+                    },
+                    info.latency.as_millis().to_string(),
+                ],
+            )
+        }
+    };
+    Ok(table)
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    match run(&cli).await {
+        Ok(table) => println!("{}", table.render(cli.output)),
+        Err(error) => {
+            eprintln!("error: {:#}", error);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+/// Rendering of command results
+pub enum Format {
+    /// Aligned columns
+    Table,
+    /// Array of objects keyed by column name
+    Json,
+    /// Comma separated values with a header line
+    Csv,
+}
+
+/// Rows printed by a command
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.table(),
+            Format::Json => self.json(),
+            Format::Csv => self.csv(),
+        }
+    }
+
+    fn table(&self) -> String {
+        let mut widths = self
+            .headers
+            .iter()
+            .map(|header| header.chars().count())
+            .collect::<Vec<_>>();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let line = |cells: Vec<&str>| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let mut output = line(self.headers.clone());
+        for row in &self.rows {
+            output.push('\n');
+            output.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        output
+    }
+
+    fn json(&self) -> String {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .zip(row)
+                    .map(|(header, cell)| {
+                        (header.to_string(), serde_json::Value::from(cell.as_str()))
+                    })
+                    .collect::<serde_json::Map<_, _>>()
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&rows).expect("rows are valid JSON")
+    }
+
+    fn csv(&self) -> String {
+        let line = |cells: Vec<&str>| {
+            cells
+                .iter()
+                .map(|cell| {
+                    if cell.contains([',', '"', '\n']) {
+                        format!("\"{}\"", cell.replace('"', "\"\""))
+                    } else {
+                        cell.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let mut output = line(self.headers.clone());
+        for row in &self.rows {
+            output.push('\n');
+            output.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        output
+    }
+}
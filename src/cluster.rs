@@ -0,0 +1,489 @@
+use crate::administration::{
+    CreateMeetingRequest, CreateMeetingResponse, EndMeetingRequest, EndMeetingResponse,
+};
+use crate::error::{BBBError, ClusterError, HttpError};
+use crate::monitoring::{GetMeetingInfoRequest, GetMeetingInfoResponse, GetMeetingsRequest};
+use crate::Bigbluebutton;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Health of a server as last observed by the cluster
+pub enum ServerHealth {
+    /// Server answered its last request
+    Healthy,
+
+    /// Server failed its last request and is skipped until the cooldown elapsed
+    Unhealthy {
+        /// When the failure was observed
+        since: Instant,
+        /// Description of the failure
+        reason: String,
+    },
+}
+
+#[derive(Debug)]
+struct ClusterServer {
+    id: String,
+    client: Bigbluebutton,
+    weight: u32,
+    health: Mutex<ServerHealth>,
+}
+
+impl ClusterServer {
+    fn is_available(&self, cooldown: Duration) -> bool {
+        match &*self.health.lock().expect("server health poisoned") {
+            ServerHealth::Healthy => true,
+            ServerHealth::Unhealthy { since, .. } => since.elapsed() >= cooldown,
+        }
+    }
+
+    fn set_health(&self, health: ServerHealth) {
+        *self.health.lock().expect("server health poisoned") = health;
+    }
+
+    fn mark_unhealthy(&self, error: &anyhow::Error) {
+        self.set_health(ServerHealth::Unhealthy {
+            since: Instant::now(),
+            reason: error.to_string(),
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Load of a server computed from `getMeetings`
+pub struct ServerLoad {
+    /// Server id
+    pub server_id: String,
+    /// Number of running meetings
+    pub meetings: u64,
+    /// Number of participants over all meetings
+    pub participants: u64,
+}
+
+#[derive(Debug, Clone)]
+/// Client for a pool of BigBlueButton servers.
+///
+/// New meetings are placed on the healthy server with the fewest participants relative to its
+/// weight. The cluster remembers which server owns a meeting and routes every later call for
+/// that meeting to it. Servers failing a request are skipped until `cooldown` has elapsed.
+///
+/// ```rust,no_run
+/// use bigbluebutton::administration::CreateMeetingRequest;
+/// use bigbluebutton::cluster::BigbluebuttonCluster;
+/// use bigbluebutton::Bigbluebutton;
+/// # async fn run() -> anyhow::Result<()> {
+/// let cluster = BigbluebuttonCluster::new()
+///     .add_server("bbb1", Bigbluebutton::new("https://bbb1.example.com/bigbluebutton/", "secret1"), 1)
+///     .add_server("bbb2", Bigbluebutton::new("https://bbb2.example.com/bigbluebutton/", "secret2"), 2);
+///
+/// let (server_id, response) = cluster.create_meeting(&CreateMeetingRequest::new("12")).await?;
+/// let join_url = cluster.join_url("12", vec![("fullName", "Alice"), ("password", response.attendee_pw().as_str())])?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct BigbluebuttonCluster {
+    servers: Vec<Arc<ClusterServer>>,
+    affinity: Arc<Mutex<HashMap<String, String>>>,
+    creating: Arc<CreateLocks>,
+    cooldown: Duration,
+}
+
+type CreateLocks = Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>;
+
+/// Lock held while a meeting is placed and created, forgotten once nobody waits for it
+struct CreateLock<'a> {
+    locks: &'a CreateLocks,
+    meeting_id: &'a str,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Drop for CreateLock<'_> {
+    fn drop(&mut self) {
+        let mut locks = self.locks.lock().expect("create locks poisoned");
+        // only the map and this guard hold the lock
+        if Arc::strong_count(&self.lock) == 2 {
+            locks.remove(self.meeting_id);
+        }
+    }
+}
+
+impl Default for BigbluebuttonCluster {
+    fn default() -> Self {
+        Self {
+            servers: Vec::new(),
+            affinity: Default::default(),
+            creating: Default::default(),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl BigbluebuttonCluster {
+    /// Creates new empty BigbluebuttonCluster
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a server, a server with weight 2 is expected to carry twice the load of weight 1
+    pub fn add_server(mut self, id: impl ToString, client: Bigbluebutton, weight: u32) -> Self {
+        self.servers.push(Arc::new(ClusterServer {
+            id: id.to_string(),
+            client,
+            weight: weight.max(1),
+            health: Mutex::new(ServerHealth::Healthy),
+        }));
+        self
+    }
+
+    /// How long an unhealthy server is skipped before it is tried again (default 30 seconds)
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Ids of the configured servers
+    pub fn server_ids(&self) -> Vec<String> {
+        self.servers
+            .iter()
+            .map(|server| server.id.clone())
+            .collect()
+    }
+
+    /// Client of a server
+    pub fn server(&self, server_id: &str) -> Option<&Bigbluebutton> {
+        self.find_server(server_id).map(|server| &server.client)
+    }
+
+    /// Last observed health of a server
+    pub fn health(&self, server_id: &str) -> Option<ServerHealth> {
+        self.find_server(server_id).map(|server| {
+            server
+                .health
+                .lock()
+                .expect("server health poisoned")
+                .clone()
+        })
+    }
+
+    /// Id of the server owning a meeting, if known
+    pub fn server_for(&self, meeting_id: &str) -> Option<String> {
+        self.affinity
+            .lock()
+            .expect("affinity poisoned")
+            .get(meeting_id)
+            .cloned()
+    }
+
+    /// Snapshot of every known meeting to server assignment
+    pub fn affinities(&self) -> HashMap<String, String> {
+        self.affinity.lock().expect("affinity poisoned").clone()
+    }
+
+    /// Records that a meeting lives on a server, e.g. when restoring persisted state
+    pub fn set_affinity(&self, meeting_id: impl ToString, server_id: impl ToString) {
+        self.affinity
+            .lock()
+            .expect("affinity poisoned")
+            .insert(meeting_id.to_string(), server_id.to_string());
+    }
+
+    /// Forgets the server owning a meeting
+    pub fn remove_affinity(&self, meeting_id: &str) {
+        self.affinity
+            .lock()
+            .expect("affinity poisoned")
+            .remove(meeting_id);
+    }
+
+    /// Polls `getMeetings` on every server, updating health and returning the load of healthy ones
+    pub async fn refresh(&self) -> Vec<ServerLoad> {
+        futures::future::join_all(self.servers.iter().map(|server| self.load_of(server)))
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Creates a meeting on the server owning it, or on the least loaded healthy server.
+    ///
+    /// Returns the id of the server the meeting was created on. A meeting is only placed again
+    /// once its owning server no longer knows it, see [BigbluebuttonCluster::placement].
+    /// When placing, a server failing to answer is skipped for the next least loaded one.
+    pub async fn create_meeting(
+        &self,
+        req: &CreateMeetingRequest,
+    ) -> Result<(String, CreateMeetingResponse), anyhow::Error> {
+        self.create_with(req.meeting_id(), |client| async move {
+            client.create_meeting(req).await
+        })
+        .await
+    }
+
+    /// Places a meeting like [BigbluebuttonCluster::create_meeting] and creates it by calling
+    /// `create` with the client of the chosen server, e.g. to send a raw create call.
+    ///
+    /// Creates of the same meeting ID are serialized, so concurrent first creates end up on the
+    /// same server. The meeting is assigned to the server once `create` succeeds, transport
+    /// errors move on to the next least loaded server and other errors are returned as is.
+    pub async fn create_with<T, F, Fut>(
+        &self,
+        meeting_id: &str,
+        create: F,
+    ) -> Result<(String, T), anyhow::Error>
+    where
+        F: Fn(Bigbluebutton) -> Fut,
+        Fut: std::future::Future<Output = anyhow::Result<T>>,
+    {
+        let lock = self.create_lock(meeting_id);
+        let _creating = lock.lock.lock().await;
+
+        let mut last_error = None;
+        for server_id in self.placement(meeting_id).await? {
+            let server = self
+                .find_server(&server_id)
+                .ok_or_else(|| ClusterError::UnknownServer(server_id.clone()))?;
+            match self.call(server, create(server.client.clone())).await {
+                Ok(response) => {
+                    self.set_affinity(meeting_id, &server.id);
+                    return Ok((server.id.clone(), response));
+                }
+                Err(error) if is_transport_error(&error) => last_error = Some(error),
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| ClusterError::NoHealthyServer.into()))
+    }
+
+    /// Ids of the servers a meeting may be created on, in order of preference.
+    ///
+    /// While the owning server returns the meeting from `getMeetingInfo`, including meetings
+    /// nobody joined yet, only the owner is returned. Once it answers `notFound` the assignment
+    /// is forgotten and the least loaded servers are returned. Fails with
+    /// [ClusterError::OwnerUnavailable] while the owner does not answer, as the meeting may
+    /// still exist there.
+    ///
+    /// Concurrent placements of a new meeting may return different servers, use
+    /// [BigbluebuttonCluster::create_with] to place and create a meeting.
+    pub async fn placement(&self, meeting_id: &str) -> Result<Vec<String>, anyhow::Error> {
+        if let Some(server) = self.owner(meeting_id) {
+            let unavailable = || ClusterError::OwnerUnavailable {
+                meeting_id: meeting_id.to_string(),
+                server_id: server.id.clone(),
+            };
+            if !server.is_available(self.cooldown) {
+                return Err(unavailable().into());
+            }
+            let mut request = GetMeetingInfoRequest::new();
+            request.meeting_id = Some(meeting_id.to_string());
+            match self
+                .call(&server, server.client.get_meeting_info(&request))
+                .await
+            {
+                Ok(_) => return Ok(vec![server.id.clone()]),
+                Err(error) if is_not_found(&error) => self.remove_affinity(meeting_id),
+                Err(error) if is_transport_error(&error) => return Err(unavailable().into()),
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(self.least_loaded_servers().await)
+    }
+
+    /// Ids of the available servers ordered from least to most loaded relative to their weight
+    pub async fn least_loaded_servers(&self) -> Vec<String> {
+        self.placement_candidates()
+            .await
+            .into_iter()
+            .map(|server| server.id.clone())
+            .collect()
+    }
+
+    /// Ids of the servers which are healthy or whose cooldown elapsed
+    pub fn available_server_ids(&self) -> Vec<String> {
+        self.servers
+            .iter()
+            .filter(|server| server.is_available(self.cooldown))
+            .map(|server| server.id.clone())
+            .collect()
+    }
+
+    /// Id of the server owning a meeting, asking every available server when it is not known
+    pub async fn locate_meeting(&self, meeting_id: &str) -> Result<String, anyhow::Error> {
+        Ok(self.locate(meeting_id).await?.id.clone())
+    }
+
+    /// Sends a raw checksummed request to a server, updating its health from the outcome
+    pub async fn request_raw<K, V>(
+        &self,
+        server_id: &str,
+        api_path: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<String, anyhow::Error>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let server = self
+            .find_server(server_id)
+            .ok_or_else(|| ClusterError::UnknownServer(server_id.to_string()))?;
+        self.call(server, server.client.request_raw(api_path, params))
+            .await
+    }
+
+    /// Generates a join URL signed by the server owning the meeting
+    pub fn join_url<K, V>(
+        &self,
+        meeting_id: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<String, anyhow::Error>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let server = self
+            .owner(meeting_id)
+            .ok_or_else(|| ClusterError::UnknownMeeting(meeting_id.to_string()))?;
+        let params = params
+            .into_iter()
+            .map(|(key, value)| (key.as_ref().to_string(), value.as_ref().to_string()))
+            .chain(std::iter::once((
+                "meetingID".to_string(),
+                meeting_id.to_string(),
+            )));
+        Ok(server.client.generate_url("join", params)?)
+    }
+
+    /// Ends a meeting on the server owning it and forgets the assignment
+    pub async fn end_meeting(
+        &self,
+        req: &EndMeetingRequest,
+    ) -> Result<EndMeetingResponse, anyhow::Error> {
+        let server = self.locate(req.meeting_id()).await?;
+        let response = self.call(&server, server.client.end_meeting(req)).await?;
+        self.remove_affinity(req.meeting_id());
+        Ok(response)
+    }
+
+    /// Returns meeting information from the server owning the meeting
+    pub async fn get_meeting_info(
+        &self,
+        req: &GetMeetingInfoRequest,
+    ) -> Result<GetMeetingInfoResponse, anyhow::Error> {
+        let meeting_id = req.meeting_id.clone().unwrap_or_default();
+        let server = self.locate(&meeting_id).await?;
+        self.call(&server, server.client.get_meeting_info(req))
+            .await
+    }
+
+    fn find_server(&self, server_id: &str) -> Option<&Arc<ClusterServer>> {
+        self.servers.iter().find(|server| server.id == server_id)
+    }
+
+    fn create_lock<'a>(&'a self, meeting_id: &'a str) -> CreateLock<'a> {
+        let lock = self
+            .creating
+            .lock()
+            .expect("create locks poisoned")
+            .entry(meeting_id.to_string())
+            .or_default()
+            .clone();
+        CreateLock {
+            locks: &self.creating,
+            meeting_id,
+            lock,
+        }
+    }
+
+    fn owner(&self, meeting_id: &str) -> Option<Arc<ClusterServer>> {
+        let server_id = self.server_for(meeting_id)?;
+        self.find_server(&server_id).cloned()
+    }
+
+    /// Finds the server owning a meeting, asking every available server when it is not known
+    async fn locate(&self, meeting_id: &str) -> Result<Arc<ClusterServer>, anyhow::Error> {
+        if let Some(server) = self.owner(meeting_id) {
+            return Ok(server);
+        }
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some(meeting_id.to_string());
+        for server in &self.servers {
+            if !server.is_available(self.cooldown) {
+                continue;
+            }
+            if self
+                .call(server, server.client.get_meeting_info(&request))
+                .await
+                .is_ok()
+            {
+                self.set_affinity(meeting_id, &server.id);
+                return Ok(server.clone());
+            }
+        }
+        Err(ClusterError::UnknownMeeting(meeting_id.to_string()).into())
+    }
+
+    async fn placement_candidates(&self) -> Vec<&Arc<ClusterServer>> {
+        let available = self
+            .servers
+            .iter()
+            .filter(|server| server.is_available(self.cooldown))
+            .collect::<Vec<_>>();
+        let loads =
+            futures::future::join_all(available.iter().map(|server| self.load_of(server))).await;
+        let mut candidates = available
+            .into_iter()
+            .zip(loads)
+            .filter_map(|(server, load)| {
+                let load = load?;
+                Some((load.participants as f64 / f64::from(server.weight), server))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        candidates.into_iter().map(|(_, server)| server).collect()
+    }
+
+    async fn load_of(&self, server: &ClusterServer) -> Option<ServerLoad> {
+        let response = self
+            .call(
+                server,
+                server.client.get_meetings(&GetMeetingsRequest::new()),
+            )
+            .await
+            .ok()?;
+        Some(ServerLoad {
+            server_id: server.id.clone(),
+            meetings: response.meetings().len() as u64,
+            participants: response
+                .meetings()
+                .iter()
+                .map(|meeting| meeting.participant_count().parse::<u64>().unwrap_or(0))
+                .sum(),
+        })
+    }
+
+    /// Runs a call against a server, updating its health from the outcome.
+    ///
+    /// Only transport failures mark the server unhealthy, BBB errors such as `notFound` mean
+    /// the server is reachable.
+    async fn call<T>(
+        &self,
+        server: &ClusterServer,
+        call: impl std::future::Future<Output = anyhow::Result<T>>,
+    ) -> anyhow::Result<T> {
+        let result = call.await;
+        match &result {
+            Err(error) if is_transport_error(error) => server.mark_unhealthy(error),
+            _ => server.set_health(ServerHealth::Healthy),
+        }
+        result
+    }
+}
+
+fn is_transport_error(error: &anyhow::Error) -> bool {
+    error.is::<reqwest::Error>() || error.is::<HttpError>()
+}
+
+fn is_not_found(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<BBBError>(), Some(error) if error.message_key == "notFound")
+}
@@ -0,0 +1,289 @@
+use crate::error::ConfigError;
+use crate::retry::RetryPolicy;
+use crate::{Bigbluebutton, BigbluebuttonBuilder, ChecksumAlgorithm};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+/// Client settings loaded from the environment, a TOML or JSON file, `bbb-conf --secret`
+/// output or a `bbb-web.properties` file.
+///
+/// ```toml
+/// url = "https://example.com/bigbluebutton/"
+/// secret = "330a8b08c3b4c61533e1d0c5ce1ac88f"
+/// checksum_algorithm = "sha256"
+/// timeout_ms = 5000
+///
+/// [retry]
+/// max_attempts = 3
+///
+/// [[servers]]
+/// id = "bbb-1"
+/// url = "https://bbb-1.example.com/bigbluebutton/"
+/// secret = "..."
+/// weight = 2
+/// ```
+pub struct Config {
+    /// Server URL
+    pub url: Option<String>,
+    /// Shared secret
+    pub secret: Option<String>,
+    /// Previous secrets still accepted while rotating
+    pub fallback_secrets: Vec<String>,
+    /// Checksum algorithm, defaults to the client default
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Timeout of a single HTTP request in milliseconds
+    pub timeout_ms: Option<u64>,
+    /// Retry policy for transient failures
+    pub retry: Option<RetryConfig>,
+    /// Cluster members
+    pub servers: Vec<ServerConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+/// Settings of a [RetryPolicy], unset values keep the policy defaults
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one
+    pub max_attempts: Option<u32>,
+    /// Delay before the first retry in milliseconds
+    pub initial_backoff_ms: Option<u64>,
+    /// Upper bound of the delay between two attempts in milliseconds
+    pub max_backoff_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+/// Member of a cluster
+pub struct ServerConfig {
+    /// Server id
+    pub id: String,
+    /// Server URL
+    pub url: String,
+    /// Shared secret of the server
+    pub secret: String,
+    /// Relative capacity of the server
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl RetryConfig {
+    /// Builds the retry policy
+    pub fn policy(&self) -> RetryPolicy {
+        let mut policy = RetryPolicy::new();
+        if let Some(max_attempts) = self.max_attempts {
+            policy = policy.max_attempts(max_attempts);
+        }
+        if let Some(initial_backoff_ms) = self.initial_backoff_ms {
+            policy = policy.initial_backoff(Duration::from_millis(initial_backoff_ms));
+        }
+        if let Some(max_backoff_ms) = self.max_backoff_ms {
+            policy = policy.max_backoff(Duration::from_millis(max_backoff_ms));
+        }
+        policy
+    }
+}
+
+impl Config {
+    /// Loads the settings from the environment.
+    ///
+    /// The file named by `BBB_CONFIG` is loaded first, then `BBB_URL`, `BBB_SECRET`,
+    /// `BBB_FALLBACK_SECRETS` (comma separated), `BBB_CHECKSUM_ALGORITHM` and `BBB_TIMEOUT_MS`
+    /// override its values.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        let mut config = match var("BBB_CONFIG") {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+        if let Some(url) = var("BBB_URL") {
+            config.url = Some(url);
+        }
+        if let Some(secret) = var("BBB_SECRET") {
+            config.secret = Some(secret);
+        }
+        if let Some(fallback_secrets) = var("BBB_FALLBACK_SECRETS") {
+            config.fallback_secrets = fallback_secrets
+                .split(',')
+                .map(str::trim)
+                .filter(|secret| !secret.is_empty())
+                .map(ToString::to_string)
+                .collect();
+        }
+        if let Some(algorithm) = var("BBB_CHECKSUM_ALGORITHM") {
+            config.checksum_algorithm = Some(algorithm.parse()?);
+        }
+        if let Some(timeout_ms) = var("BBB_TIMEOUT_MS") {
+            config.timeout_ms = Some(timeout_ms.parse().map_err(|_| {
+                ConfigError::Parse(format!("BBB_TIMEOUT_MS is not a number: {}", timeout_ms))
+            })?);
+        }
+        Ok(config)
+    }
+
+    /// Loads a TOML file, or a JSON file when the extension is `.json`
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        if path.extension().and_then(|extension| extension.to_str()) == Some("json") {
+            Ok(Self::from_json(&content)?)
+        } else {
+            Ok(Self::from_toml(&content)?)
+        }
+    }
+
+    /// Parses TOML settings
+    pub fn from_toml(content: &str) -> Result<Self, ConfigError> {
+        toml::from_str(content).map_err(|error| ConfigError::Parse(error.to_string()))
+    }
+
+    /// Parses JSON settings
+    pub fn from_json(content: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str(content).map_err(|error| ConfigError::Parse(error.to_string()))
+    }
+
+    /// Parses the output of `bbb-conf --secret`
+    ///
+    /// ```rust
+    /// use bigbluebutton::config::Config;
+    /// let output = "
+    ///     URL: https://example.com/bigbluebutton/
+    ///     Secret: 330a8b08c3b4c61533e1d0c5ce1ac88f
+    /// ";
+    /// let config = Config::from_bbb_conf(output).unwrap();
+    /// assert_eq!(config.url.as_deref(), Some("https://example.com/bigbluebutton/"));
+    /// ```
+    pub fn from_bbb_conf(output: &str) -> Result<Self, ConfigError> {
+        let value = |key: &str| {
+            output
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix(key))
+                .map(|value| value.trim().to_string())
+                .find(|value| !value.is_empty())
+        };
+        Ok(Self {
+            url: Some(value("URL:").ok_or_else(|| ConfigError::Missing("URL".to_string()))?),
+            secret: Some(
+                value("Secret:").ok_or_else(|| ConfigError::Missing("Secret".to_string()))?,
+            ),
+            ..Self::default()
+        })
+    }
+
+    /// Parses a `bbb-web.properties` file.
+    ///
+    /// Reads `bigbluebutton.web.serverURL`, `securitySalt` and `supportedChecksumAlgorithms`,
+    /// picking the strongest supported algorithm.
+    pub fn from_properties(content: &str) -> Result<Self, ConfigError> {
+        let mut server_url = None;
+        let mut secret = None;
+        let mut algorithms = None;
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+            match key {
+                "bigbluebutton.web.serverURL" => server_url = Some(value.to_string()),
+                "securitySalt" => secret = Some(value.to_string()),
+                "supportedChecksumAlgorithms" => algorithms = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        let server_url = server_url
+            .ok_or_else(|| ConfigError::Missing("bigbluebutton.web.serverURL".to_string()))?;
+        let secret = secret.ok_or_else(|| ConfigError::Missing("securitySalt".to_string()))?;
+        let checksum_algorithm = match algorithms {
+            Some(algorithms) => Some(
+                algorithms
+                    .split(',')
+                    .filter_map(|name| name.parse::<ChecksumAlgorithm>().ok())
+                    .max_by_key(|algorithm| algorithm.digest("").len())
+                    .ok_or(ConfigError::UnsupportedAlgorithm(algorithms))?,
+            ),
+            None => None,
+        };
+        Ok(Self {
+            url: Some(format!(
+                "{}/bigbluebutton/",
+                server_url.trim_end_matches('/')
+            )),
+            secret: Some(secret),
+            checksum_algorithm,
+            ..Self::default()
+        })
+    }
+
+    /// Creates a [BigbluebuttonBuilder] with these settings
+    pub fn builder(&self) -> Result<BigbluebuttonBuilder, ConfigError> {
+        let url = self
+            .url
+            .as_ref()
+            .ok_or_else(|| ConfigError::Missing("url".to_string()))?;
+        let secret = self
+            .secret
+            .as_ref()
+            .ok_or_else(|| ConfigError::Missing("secret".to_string()))?;
+        Ok(self.apply(Bigbluebutton::builder(url, secret)))
+    }
+
+    /// Creates the client described by these settings
+    pub fn client(&self) -> anyhow::Result<Bigbluebutton> {
+        Ok(self.builder()?.try_build()?)
+    }
+
+    /// Creates a cluster of the configured servers, sharing the client settings
+    #[cfg(feature = "cluster")]
+    pub fn cluster(&self) -> anyhow::Result<crate::cluster::BigbluebuttonCluster> {
+        if self.servers.is_empty() {
+            return Err(ConfigError::Missing("servers".to_string()).into());
+        }
+        let mut cluster = crate::cluster::BigbluebuttonCluster::new();
+        for server in &self.servers {
+            let client = self
+                .apply(Bigbluebutton::builder(&server.url, &server.secret))
+                .try_build()?;
+            cluster = cluster.add_server(&server.id, client, server.weight);
+        }
+        Ok(cluster)
+    }
+
+    /// Applies the settings shared by every server
+    fn apply(&self, mut builder: BigbluebuttonBuilder) -> BigbluebuttonBuilder {
+        for secret in &self.fallback_secrets {
+            builder = builder.fallback_secret(secret);
+        }
+        if let Some(checksum_algorithm) = self.checksum_algorithm {
+            builder = builder.checksum_algorithm(checksum_algorithm);
+        }
+        if let Some(timeout_ms) = self.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(retry) = &self.retry {
+            builder = builder.retry_policy(retry.policy());
+        }
+        builder
+    }
+}
+
+impl Bigbluebutton {
+    /// Creates a client from the environment, see [Config::from_env]
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
+    /// ```
+    pub fn from_env() -> anyhow::Result<Self> {
+        Config::from_env()?.client()
+    }
+}
@@ -1,5 +1,40 @@
 use serde::Deserialize;
 
+/// Query parameters whose values are masked by [sanitize_url]
+const SENSITIVE_PARAMS: &[&str] = &[
+    "checksum",
+    "password",
+    "moderatorPW",
+    "attendeePW",
+    "sessionToken",
+];
+
+/// Masks the `checksum`, `password`, `moderatorPW`, `attendeePW` and `sessionToken` query values
+/// of a URL
+///
+/// ```rust
+/// use bigbluebutton::error::sanitize_url;
+/// assert_eq!(
+///     sanitize_url("https://example.com/api/join?meetingID=1&password=mp&checksum=abc"),
+///     "https://example.com/api/join?meetingID=1&password=***&checksum=***"
+/// );
+/// ```
+pub fn sanitize_url(url: &str) -> String {
+    let (base, query) = match url.split_once('?') {
+        Some(parts) => parts,
+        None => return url.to_string(),
+    };
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if SENSITIVE_PARAMS.contains(&key) => format!("{}=***", key),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{}", base, query)
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 /// ErrorCode for BBB API
 pub enum ResponseCode {
@@ -24,3 +59,284 @@ pub struct BBBError {
     /// Error Message
     pub message: String,
 }
+
+impl std::fmt::Display for BBBError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for BBBError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single problem found while validating a request before it is sent
+pub enum Violation {
+    /// Field length is outside of the allowed range
+    InvalidLength {
+        /// BBB parameter name
+        field: &'static str,
+        /// Minimum allowed length
+        min: usize,
+        /// Maximum allowed length
+        max: usize,
+        /// Actual length
+        actual: usize,
+    },
+
+    /// Voice bridge is not a 5 digit number
+    InvalidVoiceBridge(String),
+
+    /// Field is required because another field is set
+    MissingField {
+        /// BBB parameter name which is missing
+        field: &'static str,
+        /// BBB parameter name which requires it
+        required_by: &'static str,
+    },
+
+    /// Field does not contain a valid URL
+    InvalidUrl {
+        /// BBB parameter name
+        field: &'static str,
+        /// Rejected value
+        value: String,
+    },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::InvalidLength {
+                field,
+                min,
+                max,
+                actual,
+            } => write!(
+                f,
+                "{} must be between {} and {} characters, got {}",
+                field, min, max, actual
+            ),
+            Violation::InvalidVoiceBridge(value) => {
+                write!(f, "voiceBridge must be a 5 digit number, got {:?}", value)
+            }
+            Violation::MissingField { field, required_by } => {
+                write!(f, "{} is required when {} is set", field, required_by)
+            }
+            Violation::InvalidUrl { field, value } => {
+                write!(f, "{} is not a valid URL: {:?}", field, value)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a request fails client side validation
+pub struct ValidationError {
+    /// Every violation found in the request
+    pub violations: Vec<Violation>,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid request: ")?;
+        for (index, violation) in self.violations.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when waiting for a meeting state does not complete
+pub enum WaitError {
+    /// The configured timeout elapsed before the condition was met
+    Timeout,
+
+    /// The wait was cancelled through its cancellation token
+    Cancelled,
+}
+
+impl std::fmt::Display for WaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaitError::Timeout => write!(f, "timed out waiting for meeting"),
+            WaitError::Cancelled => write!(f, "waiting for meeting was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for WaitError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when the server answers with a non-success HTTP status
+pub struct HttpError {
+    /// HTTP status code
+    pub status: u16,
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "server responded with HTTP status {}", self.status)
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned by [crate::cluster::BigbluebuttonCluster] when a request cannot be routed
+pub enum ClusterError {
+    /// Every server is unhealthy
+    NoHealthyServer,
+
+    /// No server is known to own the meeting
+    UnknownMeeting(String),
+
+    /// No server is configured with this id
+    UnknownServer(String),
+
+    /// The server owning the meeting does not answer, the meeting may still run on it
+    OwnerUnavailable {
+        /// Meeting ID
+        meeting_id: String,
+        /// Id of the owning server
+        server_id: String,
+    },
+}
+
+impl std::fmt::Display for ClusterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClusterError::NoHealthyServer => write!(f, "no healthy server available"),
+            ClusterError::UnknownMeeting(meeting_id) => {
+                write!(f, "no server owns meeting {}", meeting_id)
+            }
+            ClusterError::UnknownServer(server_id) => write!(f, "unknown server {}", server_id),
+            ClusterError::OwnerUnavailable {
+                meeting_id,
+                server_id,
+            } => write!(
+                f,
+                "server {} owning meeting {} is unavailable",
+                server_id, meeting_id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ClusterError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned by [crate::Bigbluebutton::probe]
+pub enum ProbeError {
+    /// The API root did not answer with a SUCCESS version response
+    UnexpectedResponse,
+
+    /// The server rejected the checksum, the secret or the algorithm is wrong
+    ChecksumRejected {
+        /// Algorithm used to compute the checksum
+        algorithm: String,
+    },
+}
+
+impl std::fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProbeError::UnexpectedResponse => write!(f, "unexpected response from API root"),
+            ProbeError::ChecksumRejected { algorithm } => write!(
+                f,
+                "server rejected the {} checksum, check the secret and the supported checksum algorithms",
+                algorithm
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a request sets parameters the configured server version does not support
+pub struct UnsupportedParameterError {
+    /// API of the request
+    pub api: String,
+    /// Version configured on the client
+    pub server_version: crate::version::ServerVersion,
+    /// Parameters and the version adding them, `*` when the whole API is missing
+    pub parameters: Vec<(String, crate::version::ServerVersion)>,
+}
+
+impl std::fmt::Display for UnsupportedParameterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(name, since)| format!("{} (since {})", name, since))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "{} parameters not supported by server version {}: {}",
+            self.api, self.server_version, parameters
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedParameterError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when the server URL given to the client is not usable
+pub enum BaseUrlError {
+    /// The URL could not be parsed
+    Parse(url::ParseError),
+
+    /// The URL scheme is not http or https
+    UnsupportedScheme(String),
+}
+
+impl std::fmt::Display for BaseUrlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaseUrlError::Parse(error) => write!(f, "invalid server url: {}", error),
+            BaseUrlError::UnsupportedScheme(scheme) => {
+                write!(
+                    f,
+                    "unsupported server url scheme {}, expected http or https",
+                    scheme
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BaseUrlError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when client settings cannot be loaded
+pub enum ConfigError {
+    /// A required environment variable or setting is missing
+    Missing(String),
+
+    /// The checksum algorithm is unknown or not enabled
+    UnsupportedAlgorithm(String),
+
+    /// The file content could not be parsed
+    Parse(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Missing(name) => write!(f, "missing setting {}", name),
+            ConfigError::UnsupportedAlgorithm(name) => {
+                write!(f, "unsupported checksum algorithm {}", name)
+            }
+            ConfigError::Parse(message) => write!(f, "invalid configuration: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -1,23 +1,266 @@
-use crate::Bigbluebutton;
+use crate::{Bigbluebutton, ChecksumAlgorithm};
 
 use sha2::Digest;
+use subtle::ConstantTimeEq;
 
 impl Bigbluebutton {
-    /// hash function for converting to SHA-256
-    #[cfg(not(feature = "webhook"))]
-    pub(crate) fn hash(payload: Vec<&str>) -> String {
-        let mut hasher = sha2::Sha256::new();
-        hasher.update(payload.join(""));
-        let result = hasher.finalize();
-        let hash_value = result.as_slice();
-        hex::encode(hash_value)
-    }
-    #[cfg(feature = "webhook")]
-    pub(crate) fn hash(payload: Vec<&str>) -> String {
-        let mut hasher = sha1::Sha1::new();
-        hasher.update(payload.join(""));
-        let result = hasher.finalize();
-        let hash_value = result.as_slice();
-        hex::encode(hash_value)
+    /// hash function for computing checksums with the configured algorithm
+    pub(crate) fn hash(&self, payload: Vec<&str>) -> String {
+        self.checksum_algorithm.digest(&payload.join(""))
     }
 }
+
+impl ChecksumAlgorithm {
+    /// Every algorithm available with the enabled features
+    const ALL: &'static [ChecksumAlgorithm] = &[
+        #[cfg(feature = "sha-1")]
+        ChecksumAlgorithm::Sha1,
+        ChecksumAlgorithm::Sha256,
+        ChecksumAlgorithm::Sha384,
+        ChecksumAlgorithm::Sha512,
+    ];
+
+    /// Hex encoded digest of the payload
+    pub(crate) fn digest(&self, payload: &str) -> String {
+        match self {
+            #[cfg(feature = "sha-1")]
+            ChecksumAlgorithm::Sha1 => hex::encode(sha1::Sha1::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha256 => hex::encode(sha2::Sha256::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha384 => hex::encode(sha2::Sha384::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha512 => hex::encode(sha2::Sha512::digest(payload.as_bytes())),
+        }
+    }
+
+    /// Algorithm producing hex digests of this length
+    fn from_hex_len(len: usize) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|algorithm| algorithm.digest("").len() == len)
+    }
+}
+
+impl Bigbluebutton {
+    /// Checks the checksum of an incoming API call signed with this client's secret.
+    ///
+    /// `query` is the raw query string including the `checksum` parameter. The algorithm is
+    /// detected from the checksum length, SHA-1 is only accepted when the `webhook` feature is enabled.
+    ///
+    /// ```rust
+    /// # use bigbluebutton::Bigbluebutton;
+    /// let client = Bigbluebutton::new("https://example.com/bigbluebutton/", "secret");
+    /// let url = client.generate_url("getMeetings", vec![("a", "1")]).unwrap();
+    /// let query = url.split_once('?').unwrap().1;
+    /// assert!(client.verify_checksum("getMeetings", query));
+    /// assert!(!client.verify_checksum("create", query));
+    /// ```
+    pub fn verify_checksum(&self, api_path: &str, query: &str) -> bool {
+        self.matching_secret(api_path, query).is_some()
+    }
+
+    /// Returns which configured secret signed an incoming API call, if any.
+    ///
+    /// ```rust
+    /// use bigbluebutton::{Bigbluebutton, SecretMatch};
+    /// let old = Bigbluebutton::new("https://example.com/bigbluebutton/", "old");
+    /// let url = old.generate_url("getMeetings", vec![("a", "1")]).unwrap();
+    /// let query = url.split_once('?').unwrap().1;
+    ///
+    /// let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "new")
+    ///     .fallback_secret("old")
+    ///     .build();
+    /// assert_eq!(client.matching_secret("getMeetings", query), Some(SecretMatch::Fallback(0)));
+    /// ```
+    pub fn matching_secret(&self, api_path: &str, query: &str) -> Option<crate::SecretMatch> {
+        if verify_checksum(api_path, query, self.salt.expose()) {
+            return Some(crate::SecretMatch::Primary);
+        }
+        self.fallback_salts
+            .iter()
+            .position(|secret| verify_checksum(api_path, query, secret.expose()))
+            .map(crate::SecretMatch::Fallback)
+    }
+}
+
+/// Checks the checksum of a raw query string against a secret
+pub(crate) fn verify_checksum(api_path: &str, query: &str, secret: &str) -> bool {
+    let mut checksum = None;
+    let params = query
+        .split('&')
+        .filter(|pair| match pair.strip_prefix("checksum=") {
+            Some(value) => {
+                checksum = Some(value.to_ascii_lowercase());
+                false
+            }
+            None => true,
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    let checksum = match checksum {
+        Some(checksum) => checksum,
+        None => return false,
+    };
+    let algorithm = match ChecksumAlgorithm::from_hex_len(checksum.len()) {
+        Some(algorithm) => algorithm,
+        None => return false,
+    };
+    let expected = algorithm.digest(&format!("{}{}{}", api_path, params, secret));
+    // constant time, so the response time does not tell how many characters were right
+    expected.as_bytes().ct_eq(checksum.as_bytes()).into()
+}
+
+/// Percent-encodes a query component like Java's `URLEncoder.encode(value, "UTF-8")`
+pub(crate) fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'*' | b'_' => {
+                encoded.push(char::from(byte))
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Joins parameters into a query string with [encode_component]
+pub(crate) fn encode_query<K, V>(params: impl IntoIterator<Item = (K, V)>) -> String
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    params
+        .into_iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                encode_component(key.as_ref()),
+                encode_component(value.as_ref())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Normalizes a server URL to the API base, ending with `/api/`.
+///
+/// Accepts the URL with or without trailing slash and with or without the `api` suffix.
+pub(crate) fn api_base_url(url: &str) -> Result<String, crate::error::BaseUrlError> {
+    use crate::error::BaseUrlError;
+
+    let mut url = url::Url::parse(url.trim()).map_err(BaseUrlError::Parse)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(BaseUrlError::UnsupportedScheme(url.scheme().to_string()));
+    }
+    let path = url.path().trim_end_matches('/');
+    let path = path.strip_suffix("/api").unwrap_or(path).to_string();
+    url.set_path(&format!("{}/api/", path));
+    url.set_query(None);
+    url.set_fragment(None);
+    Ok(url.to_string())
+}
+
+/// Whether a response reports a checksum mismatch
+pub(crate) fn is_checksum_error(body: &str) -> bool {
+    body.contains("<messageKey>checksumError</messageKey>")
+}
+
+/// Shared secret which never shows up in `Debug` output
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct Secret(String);
+
+impl Secret {
+    pub(crate) fn new(secret: impl ToString) -> Self {
+        Self(secret.to_string())
+    }
+
+    pub(crate) fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Secret(***)")
+    }
+}
+
+/// Masks sensitive query values and any occurrence of the shared secret in a URL
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub(crate) fn redact_url(url: &str, salt: &Secret) -> String {
+    redact_secret(&crate::error::sanitize_url(url), salt.expose())
+}
+
+/// Masks sensitive query values in the URL carried by a reqwest error
+pub(crate) fn redact_reqwest_error(mut error: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = error.url_mut() {
+        if let Ok(sanitized) = url::Url::parse(&crate::error::sanitize_url(url.as_str())) {
+            *url = sanitized;
+        }
+    }
+    error
+}
+
+fn redact_secret(value: &str, salt: &str) -> String {
+    if salt.is_empty() {
+        value.to_string()
+    } else {
+        value.replace(salt, "***")
+    }
+}
+
+/// Returns the decoded value of a query parameter
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub(crate) fn query_value(url: &str, key: &str) -> Option<String> {
+    url::Url::parse(url)
+        .ok()?
+        .query_pairs()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Records a field on the current tracing span
+#[cfg(feature = "tracing")]
+pub(crate) fn record<V: tracing::Value>(field: &str, value: V) {
+    tracing::Span::current().record(field, value);
+}
+
+/// Records a field on the current tracing span
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record<V>(_field: &str, _value: V) {}
+
+/// Records `bbb_api_requests_total` and `bbb_api_request_duration_seconds` for a finished call
+#[cfg(feature = "metrics")]
+pub(crate) fn record_metrics<T>(
+    api_path: &str,
+    result: &anyhow::Result<T>,
+    elapsed: std::time::Duration,
+) {
+    let (outcome, message_key) = match result {
+        Ok(_) => ("success", String::new()),
+        Err(error) => match error.downcast_ref::<crate::error::BBBError>() {
+            Some(error) => ("failed", error.message_key.clone()),
+            None => ("error", String::new()),
+        },
+    };
+    metrics::counter!(
+        "bbb_api_requests_total",
+        "api" => api_path.to_string(),
+        "result" => outcome,
+        "message_key" => message_key,
+    )
+    .increment(1);
+    metrics::histogram!("bbb_api_request_duration_seconds", "api" => api_path.to_string())
+        .record(elapsed.as_secs_f64());
+}
+
+/// Logs a warning through `tracing` when the feature is enabled
+#[cfg(feature = "tracing")]
+pub(crate) fn warn(message: &dyn std::fmt::Display) {
+    tracing::warn!("{}", message);
+}
+
+/// Logs a warning through `tracing` when the feature is enabled
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn warn(_message: &dyn std::fmt::Display) {}
@@ -40,10 +40,24 @@
 //! }
 //! ```
 
+/// Multi-server client with load balancing and failover
+#[cfg(feature = "cluster")]
+pub mod cluster;
+/// Client settings from the environment and configuration files
+#[cfg(feature = "config")]
+pub mod config;
 #[doc(hidden)]
 pub mod error;
 mod helper;
+/// Client side rate limiting and concurrency caps
+pub mod limit;
+/// Server health probe and capability detection
+pub mod probe;
 mod resources;
+/// Retry policies for transient failures
+pub mod retry;
+/// BigBlueButton server versions
+pub mod version;
 
 #[cfg(feature = "administration")]
 pub use resources::administration;
@@ -58,18 +72,329 @@ pub use resources::webhook;
 pub use resources::recording;
 
 /// Implementation of Bigbluebutton APIs
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Bigbluebutton {
-    salt: String,
+    salt: helper::Secret,
+    fallback_salts: Vec<helper::Secret>,
+    url: String,
+    retry_policy: Option<retry::RetryPolicy>,
+    limiter: limit::RequestLimiter,
+    server_version: Option<version::ServerVersion>,
+    compatibility: version::CompatibilityMode,
+    checksum_algorithm: ChecksumAlgorithm,
+    http: reqwest::Client,
+}
+
+/// Builder for [Bigbluebutton] clients with non default settings
+#[derive(Debug, Clone)]
+pub struct BigbluebuttonBuilder {
     url: String,
+    salt: helper::Secret,
+    fallback_salts: Vec<helper::Secret>,
+    retry_policy: Option<retry::RetryPolicy>,
+    limits: limit::Limits,
+    endpoint_limits: std::collections::HashMap<String, limit::Limits>,
+    server_version: Option<version::ServerVersion>,
+    compatibility: version::CompatibilityMode,
+    checksum_algorithm: ChecksumAlgorithm,
+    timeout: Option<std::time::Duration>,
+}
+
+/// Algorithm used to compute request checksums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksumAlgorithm {
+    /// SHA-1, the default when the `webhook` feature is enabled
+    #[cfg(feature = "sha-1")]
+    Sha1,
+    /// SHA-256
+    Sha256,
+    /// SHA-384 (BBB 2.6+)
+    Sha384,
+    /// SHA-512 (BBB 2.6+)
+    Sha512,
+}
+
+impl Default for ChecksumAlgorithm {
+    #[cfg(feature = "webhook")]
+    fn default() -> Self {
+        ChecksumAlgorithm::Sha1
+    }
+
+    #[cfg(not(feature = "webhook"))]
+    fn default() -> Self {
+        ChecksumAlgorithm::Sha256
+    }
+}
+
+impl ChecksumAlgorithm {
+    /// Name as used by bbb-web, e.g. `sha256`
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "sha-1")]
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Sha256 => "sha256",
+            ChecksumAlgorithm::Sha384 => "sha384",
+            ChecksumAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+impl std::str::FromStr for ChecksumAlgorithm {
+    type Err = error::ConfigError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            #[cfg(feature = "sha-1")]
+            "sha1" => Ok(ChecksumAlgorithm::Sha1),
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            "sha384" => Ok(ChecksumAlgorithm::Sha384),
+            "sha512" => Ok(ChecksumAlgorithm::Sha512),
+            _ => Err(error::ConfigError::UnsupportedAlgorithm(name.to_string())),
+        }
+    }
+}
+
+/// Secret which signed an incoming API call, see [Bigbluebutton::matching_secret]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretMatch {
+    /// The primary secret
+    Primary,
+
+    /// The fallback secret at this index, in the order they were added
+    Fallback(usize),
+}
+
+impl BigbluebuttonBuilder {
+    /// Adds a secret accepted besides the primary one, used while rotating secrets.
+    ///
+    /// Calls answered with `checksumError` are sent again signed with each fallback secret.
+    ///
+    /// ```rust
+    /// use bigbluebutton::Bigbluebutton;
+    ///
+    /// let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "new-secret")
+    ///     .fallback_secret("old-secret")
+    ///     .build();
+    /// ```
+    pub fn fallback_secret(mut self, secret: impl ToString) -> Self {
+        self.fallback_salts.push(helper::Secret::new(secret));
+        self
+    }
+
+    /// Retries transient failures according to the policy
+    pub fn retry_policy(mut self, retry_policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Limits the rate of requests sent to the server
+    pub fn rate_limit(mut self, rate_limit: limit::RateLimit) -> Self {
+        self.limits = self.limits.rate_limit(rate_limit);
+        self
+    }
+
+    /// Limits the number of requests waiting for a response at the same time
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.limits = self.limits.max_in_flight(max_in_flight);
+        self
+    }
+
+    /// Replaces the client limits for a single API, e.g. `getMeetingInfo`
+    ///
+    /// ```rust
+    /// use bigbluebutton::limit::{Limits, RateLimit};
+    /// use bigbluebutton::Bigbluebutton;
+    /// use std::time::Duration;
+    ///
+    /// let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "secret")
+    ///     .rate_limit(RateLimit::new(20, Duration::from_secs(1)))
+    ///     .endpoint_limits(
+    ///         "getMeetingInfo",
+    ///         Limits::new()
+    ///             .rate_limit(RateLimit::new(5, Duration::from_secs(1)))
+    ///             .max_in_flight(2),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn endpoint_limits(mut self, api_path: impl ToString, limits: limit::Limits) -> Self {
+        self.endpoint_limits.insert(api_path.to_string(), limits);
+        self
+    }
+
+    /// Version of the server, used to check requests for unsupported parameters
+    pub fn server_version(mut self, server_version: version::ServerVersion) -> Self {
+        self.server_version = Some(server_version);
+        self
+    }
+
+    /// What to do with parameters unsupported by the server version (default warn)
+    ///
+    /// ```rust
+    /// use bigbluebutton::administration::JoinMeetingRequest;
+    /// use bigbluebutton::version::{CompatibilityMode, ServerVersion};
+    /// use bigbluebutton::Bigbluebutton;
+    ///
+    /// let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "secret")
+    ///     .server_version(ServerVersion::new(2, 5, 0))
+    ///     .compatibility(CompatibilityMode::Error)
+    ///     .build();
+    /// let mut request = JoinMeetingRequest::new("12", "Alice");
+    /// request.set_role(Some("MODERATOR".to_string()));
+    /// // role needs 2.6
+    /// assert!(client.join_url(&request).is_err());
+    /// ```
+    pub fn compatibility(mut self, compatibility: version::CompatibilityMode) -> Self {
+        self.compatibility = compatibility;
+        self
+    }
+
+    /// Algorithm used to compute checksums, SHA-1 by default with the `webhook` feature and
+    /// SHA-256 otherwise
+    pub fn checksum_algorithm(mut self, checksum_algorithm: ChecksumAlgorithm) -> Self {
+        self.checksum_algorithm = checksum_algorithm;
+        self
+    }
+
+    /// Timeout of a single HTTP request, no timeout by default
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// URLs which cannot be parsed are used as is, see [BigbluebuttonBuilder::try_build].
+    pub fn build(self) -> Bigbluebutton {
+        let url = helper::api_base_url(&self.url).unwrap_or_else(|_| format!("{}api/", self.url));
+        self.build_with_url(url)
+    }
+
+    /// Builds the client after validating and normalizing the server URL.
+    ///
+    /// `https://host/bigbluebutton`, `https://host/bigbluebutton/` and
+    /// `https://host/bigbluebutton/api` all resolve to `https://host/bigbluebutton/api/`.
+    /// Schemes other than http and https are rejected.
+    pub fn try_build(self) -> Result<Bigbluebutton, error::BaseUrlError> {
+        let url = helper::api_base_url(&self.url)?;
+        Ok(self.build_with_url(url))
+    }
+
+    fn build_with_url(self, url: String) -> Bigbluebutton {
+        Bigbluebutton {
+            salt: self.salt,
+            fallback_salts: self.fallback_salts,
+            url,
+            retry_policy: self.retry_policy,
+            limiter: limit::RequestLimiter::new(&self.limits, &self.endpoint_limits),
+            server_version: self.server_version,
+            compatibility: self.compatibility,
+            checksum_algorithm: self.checksum_algorithm,
+            http: match self.timeout {
+                Some(timeout) => reqwest::Client::builder()
+                    .timeout(timeout)
+                    .build()
+                    .unwrap_or_default(),
+                None => reqwest::Client::new(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Debug for Bigbluebutton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Bigbluebutton")
+            .field("url", &self.url)
+            .field("salt", &self.salt)
+            .field("fallback_salts", &self.fallback_salts)
+            .field("retry_policy", &self.retry_policy)
+            .field("server_version", &self.server_version)
+            .field("checksum_algorithm", &self.checksum_algorithm)
+            .finish_non_exhaustive()
+    }
 }
+
 impl Bigbluebutton {
     /// creates new BBB API Client
     pub fn new(url: impl ToString, salt: impl ToString) -> Self {
-        let new_url = format!("{}api/", url.to_string());
-        Self {
-            salt: salt.to_string(),
-            url: new_url,
+        Self::builder(url, salt).build()
+    }
+
+    /// creates new BBB API Client, validating the server URL
+    ///
+    /// ```rust
+    /// # use bigbluebutton::Bigbluebutton;
+    /// let client = Bigbluebutton::try_new("https://example.com/bigbluebutton", "secret").unwrap();
+    /// let url = client.generate_url("getMeetings", Vec::<(&str, &str)>::new()).unwrap();
+    /// assert!(url.starts_with("https://example.com/bigbluebutton/api/getMeetings?"));
+    /// assert!(Bigbluebutton::try_new("ftp://example.com/bigbluebutton/", "secret").is_err());
+    /// ```
+    pub fn try_new(url: impl ToString, salt: impl ToString) -> Result<Self, error::BaseUrlError> {
+        Self::builder(url, salt).try_build()
+    }
+
+    /// creates new [BigbluebuttonBuilder]
+    pub fn builder(url: impl ToString, salt: impl ToString) -> BigbluebuttonBuilder {
+        BigbluebuttonBuilder {
+            url: url.to_string(),
+            salt: helper::Secret::new(salt),
+            fallback_salts: Vec::new(),
+            retry_policy: None,
+            limits: limit::Limits::default(),
+            endpoint_limits: Default::default(),
+            server_version: None,
+            compatibility: version::CompatibilityMode::default(),
+            checksum_algorithm: ChecksumAlgorithm::default(),
+            timeout: None,
+        }
+    }
+
+    /// Server version used to check requests, if configured or detected
+    pub fn server_version(&self) -> Option<version::ServerVersion> {
+        self.server_version
+    }
+
+    /// Sets the server version used to check requests
+    pub fn set_server_version(&mut self, server_version: Option<version::ServerVersion>) {
+        self.server_version = server_version;
+    }
+
+    /// Probes the server and keeps the reported version for checking requests
+    pub async fn detect_server_version(
+        &mut self,
+    ) -> anyhow::Result<Option<version::ServerVersion>> {
+        self.server_version = self.probe().await?.server_version;
+        Ok(self.server_version)
+    }
+
+    /// Applies the [version::CompatibilityMode] to a serialized query
+    fn check_compatibility(&self, api_path: &str, query: &str) -> anyhow::Result<()> {
+        let server_version = match self.server_version {
+            Some(server_version) => server_version,
+            None => return Ok(()),
+        };
+        if self.compatibility == version::CompatibilityMode::Ignore {
+            return Ok(());
+        }
+        let parameters = version::unsupported_parameters(api_path, query, &server_version);
+        if parameters.is_empty() {
+            return Ok(());
+        }
+        let error = error::UnsupportedParameterError {
+            api: api_path.to_string(),
+            server_version,
+            parameters,
+        };
+        match self.compatibility {
+            version::CompatibilityMode::Error => Err(error.into()),
+            version::CompatibilityMode::Print => {
+                eprintln!("warning: {}", error);
+                Ok(())
+            }
+            _ => {
+                helper::warn(&error);
+                Ok(())
+            }
         }
     }
 
@@ -78,14 +403,20 @@ impl Bigbluebutton {
         T: serde::Serialize,
     {
         let query_params = serde_qs::to_string(request)?;
-        let checksum = self::Bigbluebutton::hash(vec![api_path, &query_params, &self.salt]);
-        Ok(format!(
-            "{}{}?{}&checksum={}",
-            self.url, api_path, query_params, checksum
+        let query_params =
+            helper::encode_query(url::form_urlencoded::parse(query_params.as_bytes()));
+        self.check_compatibility(api_path, &query_params)?;
+        Ok(self.signed_url(
+            &format!("{}{}", self.url, api_path),
+            api_path,
+            &query_params,
+            &self.salt,
         ))
     }
 
     /// Generates BBB URL with checksum to interact with BBB server
+    ///
+    /// Parameters are encoded like bbb-web does, see [Bigbluebutton::encode_query].
     pub fn generate_url<K, V>(
         &self,
         api_path: &str,
@@ -95,11 +426,85 @@ impl Bigbluebutton {
         K: AsRef<str>,
         V: AsRef<str>,
     {
-        let url = reqwest::Url::parse_with_params(&self.url, params)?;
-        let query = url.query().expect("Query params not found");
-        // let query_params = self::Bigbluebutton::serialize_params(params);
-        let checksum = self::Bigbluebutton::hash(vec![api_path, &query, &self.salt]);
-        Ok(format!("{}&checksum={}", url, checksum))
+        let url = reqwest::Url::parse(&format!("{}{}", self.url, api_path))?;
+        let query = helper::encode_query(params);
+        Ok(self.signed_url(url.as_str(), api_path, &query, &self.salt))
+    }
+
+    /// Encodes query parameters the way BBB computes checksums, following Java's `URLEncoder`:
+    /// `A-Z a-z 0-9 . - * _` are kept, spaces become `+` and every other byte is percent-encoded.
+    ///
+    /// ```rust
+    /// # use bigbluebutton::Bigbluebutton;
+    /// let query = Bigbluebutton::encode_query(vec![("fullName", "Zoë Smith~*"), ("meetingID", "1")]);
+    /// assert_eq!(query, "fullName=Zo%C3%AB+Smith%7E*&meetingID=1");
+    /// ```
+    pub fn encode_query<K, V>(params: impl IntoIterator<Item = (K, V)>) -> String
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        helper::encode_query(params)
+    }
+
+    /// Replaces the checksum of a signed url with one computed from another secret
+    fn resign(&self, url: &str, api_path: &str, secret: &helper::Secret) -> String {
+        let unsigned = url
+            .rsplit_once("checksum=")
+            .map_or(url, |(unsigned, _)| unsigned);
+        let unsigned = unsigned.trim_end_matches('&').trim_end_matches('?');
+        let (base, query) = unsigned.split_once('?').unwrap_or((unsigned, ""));
+        self.signed_url(base, api_path, query, secret)
+    }
+
+    /// Appends the query and its checksum to an API url
+    fn signed_url(
+        &self,
+        url: &str,
+        api_path: &str,
+        query: &str,
+        secret: &helper::Secret,
+    ) -> String {
+        let checksum = self.hash(vec![api_path, query, secret.expose()]);
+        if query.is_empty() {
+            format!("{}?checksum={}", url, checksum)
+        } else {
+            format!("{}?{}&checksum={}", url, query, checksum)
+        }
+    }
+
+    /// Sends a checksummed request built from raw parameters and returns the XML response as is.
+    ///
+    /// Useful for APIs without a typed request. Retries and limits of the client apply.
+    pub async fn request_raw<K, V>(
+        &self,
+        api_path: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+    ) -> anyhow::Result<String>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let url = self.generate_url(api_path, params)?;
+        self.send_signed(api_path, &url, None).await
+    }
+
+    /// Sends a checksummed request like [Bigbluebutton::request_raw] with a POST body, e.g. the
+    /// presentations to pre-upload with `create`, and returns the XML response as is.
+    pub async fn request_raw_with_body<K, V>(
+        &self,
+        api_path: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        content_type: &str,
+        body: &str,
+    ) -> anyhow::Result<String>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let url = self.generate_url(api_path, params)?;
+        self.send_signed(api_path, &url, Some((content_type, body)))
+            .await
     }
 
     pub(crate) async fn dispatch<'a, R, T>(&self, api_path: &str, request: &R) -> anyhow::Result<T>
@@ -108,12 +513,291 @@ impl Bigbluebutton {
         T: serde::Deserialize<'a>,
     {
         let url = self.create_api_url(api_path, request)?;
-        let text_response = reqwest::get(&url).await?.text().await?;
-        if text_response.contains("SUCCESS") {
-            Ok(serde_xml_rs::from_str::<T>(&text_response)?)
+        self.observe(api_path, &url, self.execute(api_path, &url))
+            .await
+    }
+
+    /// Sends a checksummed request with the query as url-encoded POST body
+    #[cfg(feature = "legacy")]
+    pub(crate) async fn dispatch_form<'a, T>(
+        &self,
+        api_path: &str,
+        query: &str,
+    ) -> anyhow::Result<T>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        let url = format!("{}{}", self.url, api_path);
+        self.check_compatibility(api_path, query)?;
+        let call = async {
+            let content_type = "application/x-www-form-urlencoded";
+            let sign = |secret: &helper::Secret| {
+                let checksum = self.hash(vec![api_path, query, secret.expose()]);
+                format!("{}&checksum={}", query, checksum)
+            };
+            let form = sign(&self.salt);
+            let mut text_response = self
+                .send_with(api_path, &url, Some((content_type, form.as_str())))
+                .await?;
+            for secret in &self.fallback_salts {
+                if !helper::is_checksum_error(&text_response) {
+                    break;
+                }
+                let form = sign(secret);
+                text_response = self
+                    .send_with(api_path, &url, Some((content_type, form.as_str())))
+                    .await?;
+            }
+            Self::parse_response(&text_response)
+        };
+        self.observe(api_path, &url, call).await
+    }
+
+    /// Sends a request authenticated by a session token instead of a checksum
+    #[cfg(feature = "administration")]
+    pub(crate) async fn dispatch_unsigned<'a, R, T>(
+        &self,
+        api_path: &str,
+        request: &R,
+    ) -> anyhow::Result<T>
+    where
+        R: serde::Serialize,
+        T: serde::Deserialize<'a>,
+    {
+        let url = self.unsigned_api_url(api_path, request)?;
+        self.observe(api_path, &url, self.execute(api_path, &url))
+            .await
+    }
+
+    /// Sends a request authenticated by a session token and parses its JSON response
+    #[cfg(feature = "administration")]
+    pub(crate) async fn dispatch_unsigned_json<R, T>(
+        &self,
+        api_path: &str,
+        request: &R,
+    ) -> anyhow::Result<T>
+    where
+        R: serde::Serialize,
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.unsigned_api_url(api_path, request)?;
+        self.observe(api_path, &url, self.execute_json(api_path, &url))
+            .await
+    }
+
+    #[cfg(feature = "administration")]
+    fn unsigned_api_url<T>(&self, api_path: &str, request: &T) -> anyhow::Result<String>
+    where
+        T: serde::Serialize,
+    {
+        let query_params = serde_qs::to_string(request)?;
+        let query_params =
+            helper::encode_query(url::form_urlencoded::parse(query_params.as_bytes()));
+        self.check_compatibility(api_path, &query_params)?;
+        Ok(format!("{}{}?{}", self.url, api_path, query_params))
+    }
+
+    /// Records tracing spans and metrics around an API call
+    async fn observe<T>(
+        &self,
+        api_path: &str,
+        url: &str,
+        call: impl std::future::Future<Output = anyhow::Result<T>>,
+    ) -> anyhow::Result<T> {
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let started = std::time::Instant::now();
+
+        #[cfg(feature = "tracing")]
+        let result = {
+            use tracing::field::Empty;
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "bbb_api",
+                api = api_path,
+                meeting_id = Empty,
+                url = %helper::redact_url(url, &self.salt),
+                http.status = Empty,
+                attempts = Empty,
+                return_code = Empty,
+                message_key = Empty,
+                latency_ms = Empty,
+            );
+            if let Some(meeting_id) = helper::query_value(url, "meetingID") {
+                span.record("meeting_id", meeting_id.as_str());
+            }
+
+            let result = call.instrument(span.clone()).await;
+            let latency_ms = started.elapsed().as_millis() as u64;
+            span.record("latency_ms", latency_ms);
+            span.in_scope(|| match &result {
+                Ok(_) => tracing::debug!(latency_ms, "BBB API call succeeded"),
+                Err(error) => tracing::warn!(latency_ms, error = %error, "BBB API call failed"),
+            });
+            result
+        };
+
+        #[cfg(not(feature = "tracing"))]
+        let result = {
+            let _ = (api_path, url);
+            call.await
+        };
+
+        #[cfg(feature = "metrics")]
+        helper::record_metrics(api_path, &result, started.elapsed());
+
+        result
+    }
+
+    async fn execute<'a, T>(&self, api_path: &str, url: &str) -> anyhow::Result<T>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        let text_response = self.send_signed(api_path, url, None).await?;
+        Self::parse_response(&text_response)
+    }
+
+    /// Parses an XML response, turning FAILED responses into [error::BBBError]
+    fn parse_response<'a, T>(text_response: &str) -> anyhow::Result<T>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        #[derive(Debug, serde::Deserialize)]
+        struct ReturnCodeK {
+            returncode: self::error::ResponseCode,
+            #[serde(rename = "messageKey")]
+            message_key: Option<String>,
+        }
+
+        let status = serde_xml_rs::from_str::<ReturnCodeK>(text_response)?;
+        helper::record("return_code", format!("{:?}", status.returncode).as_str());
+        if let Some(message_key) = &status.message_key {
+            helper::record("message_key", message_key.as_str());
+        }
+        if status.returncode == self::error::ResponseCode::SUCCESS {
+            Ok(serde_xml_rs::from_str::<T>(text_response)?)
         } else {
-            let error = serde_xml_rs::from_str::<self::error::BBBError>(&text_response)?;
-            Err(anyhow::anyhow!("{}", error.message))
+            let error = serde_xml_rs::from_str::<self::error::BBBError>(text_response)?;
+            Err(error.into())
+        }
+    }
+
+    /// Like [Bigbluebutton::execute] for endpoints answering `{"response": {...}}` JSON
+    #[cfg(feature = "administration")]
+    async fn execute_json<T>(&self, api_path: &str, url: &str) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let text_response = self.send(api_path, url).await?;
+
+        #[derive(Debug, serde::Deserialize)]
+        struct EnvelopeK {
+            response: serde_json::Value,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct ReturnCodeK {
+            returncode: self::error::ResponseCode,
+        }
+
+        // bbb-web's JSON failures, e.g. of `enter`, carry no messageKey
+        #[derive(Debug, serde::Deserialize)]
+        struct FailureK {
+            returncode: self::error::ResponseCode,
+            #[serde(rename = "messageKey", default)]
+            message_key: String,
+            #[serde(default)]
+            message: String,
+        }
+
+        let response = serde_json::from_str::<EnvelopeK>(&text_response)?.response;
+        let status = serde_json::from_value::<ReturnCodeK>(response.clone())?;
+        helper::record("return_code", format!("{:?}", status.returncode).as_str());
+        if status.returncode == self::error::ResponseCode::SUCCESS {
+            Ok(serde_json::from_value(response)?)
+        } else {
+            let failure = serde_json::from_value::<FailureK>(response)?;
+            helper::record("message_key", failure.message_key.as_str());
+            Err(self::error::BBBError {
+                return_code: failure.returncode,
+                message_key: failure.message_key,
+                message: failure.message,
+            }
+            .into())
+        }
+    }
+
+    /// Sends a signed request, signing it again with every fallback secret while the server
+    /// answers `checksumError`
+    async fn send_signed(
+        &self,
+        api_path: &str,
+        url: &str,
+        body: Option<(&str, &str)>,
+    ) -> anyhow::Result<String> {
+        let mut text_response = self.send_with(api_path, url, body).await?;
+        for secret in &self.fallback_salts {
+            if !helper::is_checksum_error(&text_response) {
+                break;
+            }
+            let url = self.resign(url, api_path, secret);
+            text_response = self.send_with(api_path, &url, body).await?;
+        }
+        Ok(text_response)
+    }
+
+    async fn send(&self, api_path: &str, url: &str) -> anyhow::Result<String> {
+        self.send_with(api_path, url, None).await
+    }
+
+    /// Sends a request, as a POST when a `(content type, body)` is set
+    async fn send_with(
+        &self,
+        api_path: &str,
+        url: &str,
+        body: Option<(&str, &str)>,
+    ) -> anyhow::Result<String> {
+        let mut attempt = 1;
+        loop {
+            helper::record("attempts", attempt);
+            let permit = self.limiter.acquire(api_path).await;
+            let result = self.fetch(url, body).await;
+            drop(permit);
+            let error = match result {
+                Ok(text_response) => return Ok(text_response),
+                Err(error) => error,
+            };
+            match &self.retry_policy {
+                Some(policy) if policy.should_retry(api_path, attempt, &error) => {
+                    tokio::time::sleep(policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    async fn fetch(&self, url: &str, body: Option<(&str, &str)>) -> anyhow::Result<String> {
+        let request = match body {
+            Some((content_type, body)) => self
+                .http
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(body.to_string()),
+            None => self.http.get(url),
+        };
+        let response = request.send().await.map_err(helper::redact_reqwest_error)?;
+        let status = response.status();
+        helper::record("http.status", status.as_u16());
+        if !status.is_success() {
+            return Err(error::HttpError {
+                status: status.as_u16(),
+            }
+            .into());
         }
+        Ok(response
+            .text()
+            .await
+            .map_err(helper::redact_reqwest_error)?)
     }
 }
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Token bucket allowing `requests` calls every `per`, with bursts of up to `requests` calls
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+}
+
+impl RateLimit {
+    /// Creates new RateLimit
+    ///
+    /// ```rust
+    /// use bigbluebutton::limit::RateLimit;
+    /// use std::time::Duration;
+    /// // 10 requests per second
+    /// let limit = RateLimit::new(10, Duration::from_secs(1));
+    /// ```
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            requests: requests.max(1),
+            per,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Limits applied to the requests sent by the client
+pub struct Limits {
+    rate_limit: Option<RateLimit>,
+    max_in_flight: Option<usize>,
+}
+
+impl Limits {
+    /// Creates new Limits without any restriction
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the rate of requests
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Limits the number of requests waiting for a response at the same time
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight.max(1));
+        self
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate_limit: RateLimit) -> Self {
+        let capacity = f64::from(rate_limit.requests);
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_second: capacity / rate_limit.per.as_secs_f64().max(f64::EPSILON),
+            updated_at: Instant::now(),
+        }
+    }
+
+    /// Takes a token, or returns how long to wait until one is available
+    fn try_take(&mut self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.updated_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_second,
+            ))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Limiter {
+    bucket: Option<Mutex<TokenBucket>>,
+    semaphore: Option<Semaphore>,
+}
+
+impl Limiter {
+    fn new(limits: &Limits) -> Self {
+        Self {
+            bucket: limits
+                .rate_limit
+                .map(|rate_limit| Mutex::new(TokenBucket::new(rate_limit))),
+            semaphore: limits.max_in_flight.map(Semaphore::new),
+        }
+    }
+
+    async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        if let Some(bucket) = &self.bucket {
+            loop {
+                let result = bucket.lock().expect("rate limiter poisoned").try_take();
+                match result {
+                    Ok(()) => break,
+                    Err(wait) => tokio::time::sleep(wait).await,
+                }
+            }
+        }
+        match &self.semaphore {
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Client wide limits with per-endpoint overrides, shared by every clone of a client
+pub(crate) struct RequestLimiter {
+    default: Arc<Limiter>,
+    endpoints: HashMap<String, Arc<Limiter>>,
+}
+
+impl RequestLimiter {
+    pub(crate) fn new(default: &Limits, endpoints: &HashMap<String, Limits>) -> Self {
+        Self {
+            default: Arc::new(Limiter::new(default)),
+            endpoints: endpoints
+                .iter()
+                .map(|(api_path, limits)| (api_path.clone(), Arc::new(Limiter::new(limits))))
+                .collect(),
+        }
+    }
+
+    /// Waits until the request is allowed by the endpoint limits, or the client limits
+    /// when the endpoint has no override.
+    pub(crate) async fn acquire(&self, api_path: &str) -> Option<SemaphorePermit<'_>> {
+        self.endpoints
+            .get(api_path)
+            .unwrap_or(&self.default)
+            .acquire()
+            .await
+    }
+}
@@ -0,0 +1,143 @@
+use crate::error::{ProbeError, ResponseCode};
+use crate::version::ServerVersion;
+use crate::{helper, Bigbluebutton, SecretMatch};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Features available on a server, derived from its version.
+///
+/// Every capability is `false` when the server does not report its version.
+pub struct Capabilities {
+    /// Learning analytics dashboard (2.4+)
+    pub learning_dashboard: bool,
+    /// `meetingLayout` on create (2.4+)
+    pub meeting_layout: bool,
+    /// `disabledFeatures` on create (2.5+)
+    pub disabled_features: bool,
+    /// SHA-384 and SHA-512 checksums (2.6+)
+    pub extended_checksums: bool,
+    /// `sendChatMessage` API (2.7+)
+    pub send_chat_message: bool,
+    /// GraphQL based client (3.0+)
+    pub graphql: bool,
+}
+
+impl Capabilities {
+    /// Capabilities of a server running the given version
+    pub fn for_version(version: &ServerVersion) -> Self {
+        let since = |major, minor| *version >= ServerVersion::new(major, minor, 0);
+        Self {
+            learning_dashboard: since(2, 4),
+            meeting_layout: since(2, 4),
+            disabled_features: since(2, 5),
+            extended_checksums: since(2, 6),
+            send_chat_message: since(2, 7),
+            graphql: since(3, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Result of [Bigbluebutton::probe]
+pub struct ServerInfo {
+    /// API version reported in `<version>`
+    pub version: String,
+    /// API version reported in `<apiVersion>`, if any
+    pub api_version: Option<String>,
+    /// Server version reported in `<bbbVersion>`, if any
+    pub bbb_version: Option<String>,
+    /// Parsed `bbbVersion`
+    pub server_version: Option<ServerVersion>,
+    /// Checksum algorithm accepted by the server, e.g. `sha1` or `sha256`
+    pub checksum_algorithm: String,
+    /// Secret the server accepted, [SecretMatch::Fallback] means the primary secret is wrong
+    pub secret: SecretMatch,
+    /// Round trip time of the API root request
+    pub latency: Duration,
+    /// Features detected from the server version
+    pub capabilities: Capabilities,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiRootK {
+    returncode: ResponseCode,
+    version: String,
+    #[serde(rename = "apiVersion")]
+    api_version: Option<String>,
+    #[serde(rename = "bbbVersion")]
+    bbb_version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChecksumCheckK {
+    returncode: ResponseCode,
+    #[serde(rename = "messageKey")]
+    message_key: Option<String>,
+}
+
+impl Bigbluebutton {
+    /// Checks that the server is reachable and accepts the configured secret.
+    ///
+    /// Reads the versions from the API root, then sends `getMeetings` to validate the secret
+    /// and checksum algorithm. The call is signed with the primary secret alone first, so that
+    /// [ServerInfo::secret] tells when only a fallback secret is accepted. A checksum rejected
+    /// for every secret is reported as [ProbeError::ChecksumRejected].
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let client = Bigbluebutton::new("https://example.com/bigbluebutton/", "secret");
+    /// let info = client.probe().await?;
+    /// println!("BBB {:?} answered in {:?}", info.bbb_version, info.latency);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn probe(&self) -> anyhow::Result<ServerInfo> {
+        let started = Instant::now();
+        let root = self.send("", self.url.trim_end_matches('/')).await?;
+        let latency = started.elapsed();
+        let root = serde_xml_rs::from_str::<ApiRootK>(&root)?;
+        if root.returncode != ResponseCode::SUCCESS {
+            return Err(ProbeError::UnexpectedResponse.into());
+        }
+
+        let checksum_algorithm = self.checksum_algorithm.name().to_string();
+        let url = self.generate_url("getMeetings", Vec::<(&str, &str)>::new())?;
+        let mut secret = SecretMatch::Primary;
+        let mut check = self.send("getMeetings", &url).await?;
+        for (index, fallback) in self.fallback_salts.iter().enumerate() {
+            if !helper::is_checksum_error(&check) {
+                break;
+            }
+            let url = self.resign(&url, "getMeetings", fallback);
+            check = self.send("getMeetings", &url).await?;
+            secret = SecretMatch::Fallback(index);
+        }
+        let check = serde_xml_rs::from_str::<ChecksumCheckK>(&check)?;
+        if check.returncode != ResponseCode::SUCCESS
+            && check.message_key.as_deref() == Some("checksumError")
+        {
+            return Err(ProbeError::ChecksumRejected {
+                algorithm: checksum_algorithm,
+            }
+            .into());
+        }
+
+        let server_version = root.bbb_version.as_deref().and_then(ServerVersion::parse);
+        Ok(ServerInfo {
+            version: root.version,
+            api_version: root.api_version,
+            bbb_version: root.bbb_version,
+            capabilities: server_version
+                .as_ref()
+                .map(Capabilities::for_version)
+                .unwrap_or_default(),
+            server_version,
+            checksum_algorithm,
+            secret,
+            latency,
+        })
+    }
+}
@@ -1,6 +1,25 @@
-use crate::{error::ResponseCode, Bigbluebutton};
+#[cfg(feature = "monitoring")]
+use crate::error::BBBError;
+use crate::error::{ResponseCode, ValidationError, Violation};
+use crate::Bigbluebutton;
 use getset::{Getters, MutGetters, Setters};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Breakout room orchestration
+#[cfg(feature = "monitoring")]
+pub mod breakout;
+
+/// Typed client settings and user data
+pub mod settings;
+
+/// Flash client configuration of BBB 2.2 and older
+#[cfg(feature = "legacy")]
+pub mod legacy;
+
+/// Ending every meeting before server maintenance
+#[cfg(feature = "monitoring")]
+pub mod maintenance;
 
 #[derive(Getters, Setters, MutGetters, Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -116,6 +135,28 @@ pub struct CreateMeetingRequest {
 
     /// Default guestPolicy=ALWAYS_ACCEPT. Will set the guest policy for the meeting. The guest policy determines whether or not users who send a join request with guest=true will be allowed to join the meeting. Possible values are ALWAYS_ACCEPT, ALWAYS_DENY, and ASK_MODERATOR.
     guest_policy: Option<String>,
+
+    /// Default endWhenNoModerator=false. Setting to true ends the meeting when no moderator is present for a while. (2.3+)
+    end_when_no_moderator: Option<bool>,
+
+    /// Layout applied when the meeting starts, e.g. CUSTOM_LAYOUT, SMART_LAYOUT, PRESENTATION_FOCUS or VIDEO_FOCUS. (2.4+)
+    meeting_layout: Option<String>,
+
+    /// Default learningDashboardEnabled=true. Setting to false disables the learning analytics dashboard. (2.4+)
+    learning_dashboard_enabled: Option<bool>,
+
+    /// Comma separated list of features to disable, e.g. chat,sharedNotes,polls. (2.5+)
+    disabled_features: Option<String>,
+
+    /// Default notifyRecordingIsOn=false. Setting to true asks users to accept being recorded. (2.5+)
+    notify_recording_is_on: Option<bool>,
+
+    /// URL of a presentation to pre-upload, without sending it in a POST body. (2.7+)
+    pre_uploaded_presentation: Option<String>,
+
+    /// Client settings overridden for this meeting, sent as JSON. (2.7+)
+    #[serde(serialize_with = "settings::serialize_override", skip_deserializing)]
+    client_settings_override: Option<settings::ClientSettingsOverride>,
 }
 
 #[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
@@ -187,6 +228,239 @@ pub struct EndMeetingRequest {
     password: String,
 }
 
+#[derive(Getters, Setters, MutGetters, Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Joins a user to a running meeting.
+pub struct JoinMeetingRequest {
+    /// The full name that is to be used to identify this user to other conference attendees.
+    full_name: String,
+
+    #[serde(rename = "meetingID")]
+    /// The meeting ID that identifies the meeting you are attempting to join.
+    meeting_id: String,
+
+    /// The password of the meeting, moderator or attendee. Not needed when role is set on 2.6+.
+    password: Option<String>,
+
+    /// Role of the user, MODERATOR or VIEWER. (2.6+)
+    role: Option<String>,
+
+    /// Third-party-time value that must match the create time of the meeting.
+    create_time: Option<String>,
+
+    #[serde(rename = "userID")]
+    /// An identifier for this user that will help your application to identify which person this is.
+    user_id: Option<String>,
+
+    /// If you want to pass in a custom voice-extension when a user joins the voice conference using voip.
+    web_voice_conf: Option<String>,
+
+    /// Token returned by setConfigXML, to join with a custom client configuration.
+    config_token: Option<String>,
+
+    #[serde(rename = "avatarURL")]
+    /// The link for the user’s avatar to be displayed.
+    avatar_url: Option<String>,
+
+    /// Default redirect=true. Setting to false returns an XML response with the session token instead of redirecting.
+    redirect: Option<bool>,
+
+    /// Set to true to indicate that the user is a guest.
+    guest: Option<bool>,
+
+    /// Setting to true hides the user from the learning analytics dashboard. (2.4+)
+    exclude_from_dashboard: Option<bool>,
+
+    /// URL the user is sent to when the join fails, instead of an XML error. (2.7+)
+    error_redirect_url: Option<String>,
+
+    /// `userdata-bbb_*` parameters changing the client behaviour for this user
+    #[serde(flatten)]
+    user_data: settings::UserData,
+}
+
+impl JoinMeetingRequest {
+    /// Creates new JoinMeetingRequest
+    pub fn new(meeting_id: impl ToString, full_name: impl ToString) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            full_name: full_name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Response return from [Bigbluebutton::join], a join with `redirect=false`
+pub struct JoinMeetingResponse {
+    #[serde(rename = "returncode")]
+    /// Return code
+    return_code: ResponseCode,
+
+    #[serde(rename = "messageKey")]
+    /// Message Key
+    message_key: String,
+
+    /// Message
+    message: String,
+
+    /// Internal meeting ID
+    meeting_id: String,
+
+    /// Internal user ID
+    user_id: String,
+
+    /// Authentication token of the user
+    auth_token: String,
+
+    /// Session token, used by [Bigbluebutton::enter] and [Bigbluebutton::get_join_url]
+    session_token: String,
+
+    #[serde(rename = "guestStatus", default)]
+    /// Guest status, ALLOW, DENY or WAIT
+    guest_status: Option<String>,
+
+    /// URL of the client for this session
+    url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Reads the details of a joined user's session. Authenticated by the session token, without checksum.
+pub struct EnterRequest {
+    /// Session token returned by a join with `redirect=false`
+    session_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Session details returned from [EnterRequest]
+pub struct EnterResponse {
+    #[serde(rename = "returncode")]
+    /// Return code
+    return_code: ResponseCode,
+
+    #[serde(rename = "fullname")]
+    /// Full name of the user
+    full_name: String,
+
+    #[serde(rename = "confname")]
+    /// Meeting name
+    conf_name: String,
+
+    #[serde(rename = "meetingID")]
+    /// Internal meeting ID
+    meeting_id: String,
+
+    #[serde(rename = "externMeetingID")]
+    /// Meeting ID passed to create
+    extern_meeting_id: String,
+
+    #[serde(rename = "externUserID")]
+    /// User ID passed to join
+    extern_user_id: String,
+
+    #[serde(rename = "internalUserID")]
+    /// Internal user ID
+    internal_user_id: String,
+
+    #[serde(rename = "authToken")]
+    /// Authentication token of the user
+    auth_token: String,
+
+    /// Role of the user, MODERATOR or VIEWER
+    role: String,
+
+    #[serde(rename = "guestStatus", default)]
+    /// Guest status, ALLOW, DENY or WAIT
+    guest_status: Option<String>,
+
+    #[serde(rename = "voicebridge", default)]
+    /// Voice bridge of the meeting
+    voice_bridge: Option<String>,
+
+    #[serde(rename = "logoutUrl", default)]
+    /// URL the user is sent to when leaving
+    logout_url: Option<String>,
+
+    #[serde(default)]
+    /// Welcome message
+    welcome: Option<String>,
+
+    #[serde(rename = "isBreakout", default)]
+    /// Whether the meeting is a breakout room
+    is_breakout: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Exchanges a session token for a new join URL of the same user (3.0+).
+/// Authenticated by the session token, without checksum.
+pub struct GetJoinUrlRequest {
+    /// Session token returned by a join with `redirect=false`
+    session_token: String,
+
+    /// Join parameters overriding the ones of the original join, e.g. `userdata-bbb_*`
+    #[serde(flatten)]
+    params: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Response return from [GetJoinUrlRequest]
+pub struct GetJoinUrlResponse {
+    #[serde(rename = "returncode")]
+    /// Return code
+    return_code: ResponseCode,
+
+    /// Message Key
+    #[serde(default)]
+    message_key: Option<String>,
+
+    /// Message
+    #[serde(default)]
+    message: Option<String>,
+
+    /// New join URL
+    url: String,
+}
+
+impl EnterRequest {
+    /// Creates new EnterRequest
+    pub fn new(session_token: impl ToString) -> Self {
+        Self {
+            session_token: session_token.to_string(),
+        }
+    }
+}
+
+impl GetJoinUrlRequest {
+    /// Creates new GetJoinUrlRequest
+    ///
+    /// ```rust
+    /// use bigbluebutton::administration::GetJoinUrlRequest;
+    /// let request = GetJoinUrlRequest::new("token").param("userdata-bbb_auto_join_audio", "false");
+    /// assert_eq!(request.params()["userdata-bbb_auto_join_audio"], "false");
+    /// ```
+    pub fn new(session_token: impl ToString) -> Self {
+        Self {
+            session_token: session_token.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a join parameter to override
+    pub fn param(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
 #[getset(get = "pub", set = "pub", get_mut = "pub")]
 #[serde(rename_all = "camelCase")]
@@ -203,6 +477,127 @@ pub struct EndMeetingResponse {
     message: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Posts a message into the public chat of a running meeting (2.7+)
+pub struct SendChatMessageRequest {
+    #[serde(rename = "meetingID")]
+    /// The meeting ID of the running meeting
+    meeting_id: String,
+
+    /// The chat message, between 1 and 500 characters
+    message: String,
+
+    /// Sender name displayed in the chat, the server uses `System` when unset
+    user_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Response return from [SendChatMessageRequest]
+pub struct SendChatMessageResponse {
+    #[serde(rename = "returncode")]
+    /// Return code
+    return_code: ResponseCode,
+
+    /// Message Key
+    #[serde(default)]
+    message_key: Option<String>,
+
+    /// Message
+    #[serde(default)]
+    message: Option<String>,
+}
+
+#[cfg(feature = "monitoring")]
+#[derive(Debug, Clone)]
+/// Outcome of [Bigbluebutton::ensure_meeting]
+pub enum EnsureMeetingOutcome {
+    /// The meeting did not exist and has been created
+    Created(CreateMeetingResponse),
+
+    /// The meeting was already running with the same parameters.
+    /// The response carries the existing meeting's passwords and internal meeting ID.
+    AlreadyRunning(CreateMeetingResponse),
+
+    /// A meeting with the same ID is running with different parameters
+    ParameterMismatch(Box<MeetingMismatch>),
+}
+
+#[cfg(feature = "monitoring")]
+impl EnsureMeetingOutcome {
+    /// Returns the create response unless the parameters did not match
+    pub fn response(&self) -> Option<&CreateMeetingResponse> {
+        match self {
+            EnsureMeetingOutcome::Created(response)
+            | EnsureMeetingOutcome::AlreadyRunning(response) => Some(response),
+            EnsureMeetingOutcome::ParameterMismatch(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "monitoring")]
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+/// A running meeting whose parameters differ from the requested ones
+pub struct MeetingMismatch {
+    /// BBB parameter names whose requested value differs from the running meeting
+    fields: Vec<&'static str>,
+
+    /// The running meeting
+    meeting: crate::monitoring::GetMeetingInfoResponse,
+
+    /// Error returned by create if the server refused the request, e.g. `idNotUnique`
+    error: Option<BBBError>,
+}
+
+#[cfg(feature = "monitoring")]
+impl MeetingMismatch {
+    fn new(
+        req: &CreateMeetingRequest,
+        meeting: crate::monitoring::GetMeetingInfoResponse,
+        error: Option<BBBError>,
+    ) -> Self {
+        let duration = req.duration.map(|duration| duration.to_string());
+        let fields = [
+            ("name", &req.name, meeting.meeting_name()),
+            ("attendeePW", &req.attendee_pw, meeting.attendee_pw()),
+            ("moderatorPW", &req.moderator_pw, meeting.moderator_pw()),
+            ("voiceBridge", &req.voice_bridge, meeting.voice_bridge()),
+            ("dialNumber", &req.dial_number, meeting.dial_number()),
+            ("duration", &duration, meeting.duration()),
+        ]
+        .into_iter()
+        .filter(
+            |(_, requested, running)| matches!(requested, Some(requested) if requested != *running),
+        )
+        .map(|(field, _, _)| field)
+        .collect();
+        Self {
+            fields,
+            meeting,
+            error,
+        }
+    }
+}
+
+#[cfg(feature = "monitoring")]
+impl std::fmt::Display for MeetingMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error {
+            Some(error) if self.fields.is_empty() => write!(f, "{}", error),
+            _ => write!(
+                f,
+                "meeting {} is running with a different {}",
+                self.meeting.meeting_id(),
+                self.fields.join(", ")
+            ),
+        }
+    }
+}
+
 impl CreateMeetingRequest {
     /// Creates new CreateMeetingRequest
     ///
@@ -219,6 +614,155 @@ impl CreateMeetingRequest {
             ..Default::default()
         }
     }
+
+    /// Validates the request before it is sent to the server.
+    ///
+    /// Every violation is collected so that all problems can be reported at once.
+    ///
+    /// ```rust
+    /// use bigbluebutton::administration::CreateMeetingRequest;
+    /// let mut request = CreateMeetingRequest::new("1");
+    /// request.set_voice_bridge(Some("123".to_string()));
+    /// let error = request.validate().unwrap_err();
+    /// assert_eq!(error.violations.len(), 2);
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut violations = Vec::new();
+
+        check_length(&mut violations, "meetingID", &self.meeting_id);
+        if let Some(name) = &self.name {
+            check_length(&mut violations, "name", name);
+        }
+
+        if let Some(voice_bridge) = &self.voice_bridge {
+            if voice_bridge.len() != 5 || !voice_bridge.chars().all(|c| c.is_ascii_digit()) {
+                violations.push(Violation::InvalidVoiceBridge(voice_bridge.clone()));
+            }
+        }
+
+        if self.is_breakout == Some(true) {
+            if self.parent_meeting_id.is_none() {
+                violations.push(Violation::MissingField {
+                    field: "parentMeetingID",
+                    required_by: "isBreakout",
+                });
+            }
+            if self.sequence.is_none() {
+                violations.push(Violation::MissingField {
+                    field: "sequence",
+                    required_by: "isBreakout",
+                });
+            }
+        } else if self.parent_meeting_id.is_some() {
+            violations.push(Violation::MissingField {
+                field: "isBreakout",
+                required_by: "parentMeetingID",
+            });
+        }
+
+        check_url(&mut violations, "logoutURL", &self.logout_url);
+        check_url(&mut violations, "logo", &self.logo);
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { violations })
+        }
+    }
+}
+
+const MIN_IDENTIFIER_LENGTH: usize = 2;
+const MAX_IDENTIFIER_LENGTH: usize = 256;
+
+const MIN_CHAT_MESSAGE_LENGTH: usize = 1;
+const MAX_CHAT_MESSAGE_LENGTH: usize = 500;
+const MIN_CHAT_USER_NAME_LENGTH: usize = 1;
+const MAX_CHAT_USER_NAME_LENGTH: usize = 255;
+
+fn check_length(violations: &mut Vec<Violation>, field: &'static str, value: &str) {
+    check_length_between(
+        violations,
+        field,
+        value,
+        MIN_IDENTIFIER_LENGTH,
+        MAX_IDENTIFIER_LENGTH,
+    );
+}
+
+fn check_length_between(
+    violations: &mut Vec<Violation>,
+    field: &'static str,
+    value: &str,
+    min: usize,
+    max: usize,
+) {
+    let actual = value.chars().count();
+    if !(min..=max).contains(&actual) {
+        violations.push(Violation::InvalidLength {
+            field,
+            min,
+            max,
+            actual,
+        });
+    }
+}
+
+fn check_url(violations: &mut Vec<Violation>, field: &'static str, value: &Option<String>) {
+    if let Some(value) = value {
+        if url::Url::parse(value).is_err() {
+            violations.push(Violation::InvalidUrl {
+                field,
+                value: value.clone(),
+            });
+        }
+    }
+}
+
+impl SendChatMessageRequest {
+    /// Creates new SendChatMessageRequest
+    ///
+    /// ```rust
+    /// use bigbluebutton::administration::SendChatMessageRequest;
+    /// let mut request = SendChatMessageRequest::new("12", "The meeting ends in 5 minutes");
+    /// request.set_user_name(Some("Scheduler".to_string()));
+    /// assert!(request.validate().is_ok());
+    /// ```
+    pub fn new(meeting_id: impl ToString, message: impl ToString) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Checks the parameter lengths accepted by the server.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut violations = Vec::new();
+
+        check_length(&mut violations, "meetingID", &self.meeting_id);
+        check_length_between(
+            &mut violations,
+            "message",
+            &self.message,
+            MIN_CHAT_MESSAGE_LENGTH,
+            MAX_CHAT_MESSAGE_LENGTH,
+        );
+        if let Some(user_name) = &self.user_name {
+            check_length_between(
+                &mut violations,
+                "userName",
+                user_name,
+                MIN_CHAT_USER_NAME_LENGTH,
+                MAX_CHAT_USER_NAME_LENGTH,
+            );
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { violations })
+        }
+    }
 }
 
 impl EndMeetingRequest {
@@ -232,12 +776,165 @@ impl EndMeetingRequest {
 }
 
 impl Bigbluebutton {
+    /// Creates a meeting after validating the request with [CreateMeetingRequest::validate].
+    ///
+    /// Validation failures are returned as [ValidationError].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_meeting(
         &self,
         req: &CreateMeetingRequest,
+    ) -> Result<CreateMeetingResponse, anyhow::Error> {
+        req.validate()?;
+        self.create_meeting_unchecked(req).await
+    }
+
+    /// Creates a meeting without running client side validation.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_meeting_unchecked(
+        &self,
+        req: &CreateMeetingRequest,
     ) -> Result<CreateMeetingResponse, anyhow::Error> {
         self.dispatch("create", req).await
     }
+    /// Creates the meeting if it does not exist yet, otherwise returns the running meeting.
+    ///
+    /// If the meeting is already running, its parameters are fetched with getMeetingInfo and
+    /// compared with the requested ones, since the server accepts a create call for a running
+    /// meeting as long as the passwords match.
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::{CreateMeetingRequest, EnsureMeetingOutcome};
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let request = CreateMeetingRequest::new("12");
+    /// match client.ensure_meeting(&request).await? {
+    ///     EnsureMeetingOutcome::Created(response) => println!("created {}", response.meeting_id()),
+    ///     EnsureMeetingOutcome::AlreadyRunning(response) => println!("joined {}", response.meeting_id()),
+    ///     EnsureMeetingOutcome::ParameterMismatch(mismatch) => println!("mismatch: {}", mismatch),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "monitoring")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn ensure_meeting(
+        &self,
+        req: &CreateMeetingRequest,
+    ) -> Result<EnsureMeetingOutcome, anyhow::Error> {
+        match self.create_meeting(req).await {
+            Ok(response) if response.message_key() == "duplicateWarning" => {
+                let mismatch = MeetingMismatch::new(req, self.running_meeting(req).await?, None);
+                if mismatch.fields.is_empty() {
+                    Ok(EnsureMeetingOutcome::AlreadyRunning(response))
+                } else {
+                    Ok(EnsureMeetingOutcome::ParameterMismatch(Box::new(mismatch)))
+                }
+            }
+            Ok(response) => Ok(EnsureMeetingOutcome::Created(response)),
+            Err(error) => match error.downcast::<BBBError>() {
+                Ok(error) if error.message_key == "idNotUnique" => {
+                    let meeting = self.running_meeting(req).await?;
+                    Ok(EnsureMeetingOutcome::ParameterMismatch(Box::new(
+                        MeetingMismatch::new(req, meeting, Some(error)),
+                    )))
+                }
+                Ok(error) => Err(error.into()),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
+    #[cfg(feature = "monitoring")]
+    async fn running_meeting(
+        &self,
+        req: &CreateMeetingRequest,
+    ) -> Result<crate::monitoring::GetMeetingInfoResponse, anyhow::Error> {
+        let mut request = crate::monitoring::GetMeetingInfoRequest::new();
+        request.meeting_id = Some(req.meeting_id.clone());
+        self.get_meeting_info(&request).await
+    }
+
+    /// Generates the checksummed URL a user opens to join a meeting
+    ///
+    /// ```rust
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::JoinMeetingRequest;
+    /// let client = Bigbluebutton::new("https://server.com/bigbluebutton/", "secret");
+    /// let mut request = JoinMeetingRequest::new("12", "Alice");
+    /// request.set_password(Some("ap".to_string()));
+    /// let url = client.join_url(&request).unwrap();
+    /// assert!(url.starts_with("https://server.com/bigbluebutton/api/join?fullName=Alice"));
+    /// ```
+    pub fn join_url(&self, req: &JoinMeetingRequest) -> Result<String, anyhow::Error> {
+        self.create_api_url("join", req)
+    }
+
+    /// Joins a user without redirecting, returning the session token for a custom frontend.
+    ///
+    /// `redirect` is always sent as `false`.
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::{EnterRequest, JoinMeetingRequest};
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let mut request = JoinMeetingRequest::new("12", "Alice");
+    /// request.set_role(Some("VIEWER".to_string()));
+    /// let joined = client.join(&request).await?;
+    /// let session = client.enter(&EnterRequest::new(joined.session_token())).await?;
+    /// println!("{} joined {}", session.full_name(), session.conf_name());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn join(
+        &self,
+        req: &JoinMeetingRequest,
+    ) -> Result<JoinMeetingResponse, anyhow::Error> {
+        let mut req = req.clone();
+        req.redirect = Some(false);
+        self.dispatch("join", &req).await
+    }
+
+    /// Returns the session details of a joined user.
+    ///
+    /// An invalid or expired session token fails with a [crate::error::BBBError] whose
+    /// `message_key` is empty, as bbb-web only sends a message.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn enter(&self, req: &EnterRequest) -> Result<EnterResponse, anyhow::Error> {
+        self.dispatch_unsigned_json("enter", req).await
+    }
+
+    /// Exchanges a session token for a new join URL (3.0+)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_join_url(
+        &self,
+        req: &GetJoinUrlRequest,
+    ) -> Result<GetJoinUrlResponse, anyhow::Error> {
+        self.dispatch_unsigned("getJoinUrl", req).await
+    }
+
+    /// Posts a message into the public chat of a running meeting after validating the request
+    /// with [SendChatMessageRequest::validate].
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::SendChatMessageRequest;
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let request = SendChatMessageRequest::new("12", "The meeting ends in 5 minutes");
+    /// client.send_chat_message(&request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn send_chat_message(
+        &self,
+        req: &SendChatMessageRequest,
+    ) -> Result<SendChatMessageResponse, anyhow::Error> {
+        req.validate()?;
+        self.dispatch("sendChatMessage", req).await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn end_meeting(
         &self,
         req: &EndMeetingRequest,
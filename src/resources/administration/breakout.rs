@@ -0,0 +1,308 @@
+use super::{CreateMeetingRequest, EndMeetingRequest};
+use crate::error::{ValidationError, Violation};
+use crate::monitoring::GetMeetingInfoRequest;
+use crate::Bigbluebutton;
+use getset::{Getters, MutGetters, Setters};
+
+#[derive(Debug, Clone, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// A participant assigned to a breakout room
+pub struct BreakoutParticipant {
+    /// Full name displayed in the client
+    full_name: String,
+
+    /// Optional user id passed to the join call
+    user_id: Option<String>,
+
+    /// Whether the participant joins as a moderator
+    moderator: bool,
+}
+
+impl BreakoutParticipant {
+    /// Creates new viewer BreakoutParticipant
+    pub fn new(full_name: impl ToString) -> Self {
+        Self {
+            full_name: full_name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// A breakout room to create along with its participants
+pub struct BreakoutRoom {
+    /// Name of the breakout room
+    name: String,
+
+    /// Participants assigned to this room
+    participants: Vec<BreakoutParticipant>,
+}
+
+impl BreakoutRoom {
+    /// Creates new BreakoutRoom
+    pub fn new(name: impl ToString, participants: Vec<BreakoutParticipant>) -> Self {
+        Self {
+            name: name.to_string(),
+            participants,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Describes the breakout rooms to create for a running meeting
+pub struct BreakoutPlan {
+    /// Meeting ID of the running parent meeting
+    parent_meeting_id: String,
+
+    /// Rooms to create, sequence numbers are assigned in order starting from 1
+    rooms: Vec<BreakoutRoom>,
+
+    /// If set to true, users can choose the breakout room they want to join
+    free_join: Option<bool>,
+
+    /// The maximum length (in minutes) of every breakout room
+    duration: Option<u64>,
+
+    /// Whether breakout rooms are recorded
+    record: Option<bool>,
+}
+
+impl BreakoutPlan {
+    /// Creates new BreakoutPlan
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::breakout::{BreakoutParticipant, BreakoutPlan, BreakoutRoom};
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let plan = BreakoutPlan::new(
+    ///     "class-1",
+    ///     vec![
+    ///         BreakoutRoom::new("Group A", vec![BreakoutParticipant::new("Alice")]),
+    ///         BreakoutRoom::new("Group B", vec![BreakoutParticipant::new("Bob")]),
+    ///     ],
+    /// );
+    /// let session = client.create_breakout_rooms(&plan).await?;
+    /// client.end_breakout_rooms(&session).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(parent_meeting_id: impl ToString, rooms: Vec<BreakoutRoom>) -> Self {
+        Self {
+            parent_meeting_id: parent_meeting_id.to_string(),
+            rooms,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+/// Join URL generated for a participant of a breakout room
+pub struct BreakoutJoinUrl {
+    /// Participant the URL was generated for
+    participant: BreakoutParticipant,
+
+    /// Checksummed join URL
+    url: String,
+}
+
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+/// A breakout room created on the server
+pub struct CreatedBreakoutRoom {
+    /// Name of the breakout room
+    name: String,
+
+    /// Sequence number of the breakout room
+    sequence: u64,
+
+    /// Meeting ID of the breakout room
+    meeting_id: String,
+
+    /// Internal Meeting ID of the breakout room
+    internal_meeting_id: String,
+
+    /// Attendee password of the breakout room
+    attendee_pw: String,
+
+    /// Moderator password of the breakout room
+    moderator_pw: String,
+
+    /// Join URLs of the assigned participants
+    join_urls: Vec<BreakoutJoinUrl>,
+
+    /// Whether the parent meeting reports this room in its breakout list
+    tracked: bool,
+}
+
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+/// Breakout rooms created by [Bigbluebutton::create_breakout_rooms]
+pub struct BreakoutSession {
+    /// Meeting ID of the parent meeting
+    parent_meeting_id: String,
+
+    /// Internal Meeting ID of the parent meeting
+    parent_internal_meeting_id: String,
+
+    /// Created breakout rooms
+    rooms: Vec<CreatedBreakoutRoom>,
+}
+
+impl Bigbluebutton {
+    /// Creates every breakout room of the plan and generates join URLs for the assigned participants.
+    ///
+    /// The parent meeting must be running. Room names may be shorter than the two characters
+    /// required for meeting names, only the generated meeting IDs are validated. If a room cannot
+    /// be created, the rooms created before it are ended again.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_breakout_rooms(
+        &self,
+        plan: &BreakoutPlan,
+    ) -> Result<BreakoutSession, anyhow::Error> {
+        let mut info_request = GetMeetingInfoRequest::new();
+        info_request.meeting_id = Some(plan.parent_meeting_id.clone());
+        let parent = self.get_meeting_info(&info_request).await?;
+        if parent.running() != "true" {
+            return Err(anyhow::anyhow!(
+                "parent meeting {} is not running",
+                plan.parent_meeting_id
+            ));
+        }
+
+        let requests = room_requests(plan, parent.internal_meeting_id())?;
+        let mut session = BreakoutSession {
+            parent_meeting_id: plan.parent_meeting_id.clone(),
+            parent_internal_meeting_id: parent.internal_meeting_id().clone(),
+            rooms: Vec::with_capacity(plan.rooms.len()),
+        };
+        if let Err(error) = self.create_rooms(plan, &requests, &mut session).await {
+            // the error of the failed room is more useful than a failure to clean up
+            let _ = self.end_breakout_rooms(&session).await;
+            return Err(error);
+        }
+        Ok(session)
+    }
+
+    async fn create_rooms(
+        &self,
+        plan: &BreakoutPlan,
+        requests: &[CreateMeetingRequest],
+        session: &mut BreakoutSession,
+    ) -> Result<(), anyhow::Error> {
+        for (room, request) in plan.rooms.iter().zip(requests) {
+            let response = self.create_meeting_unchecked(request).await?;
+            // pushed before the join URLs so that the room is ended if they cannot be generated
+            session.rooms.push(CreatedBreakoutRoom {
+                name: room.name.clone(),
+                sequence: request.sequence().unwrap_or_default(),
+                meeting_id: response.meeting_id().clone(),
+                internal_meeting_id: response.internal_meeting_id().clone(),
+                attendee_pw: response.attendee_pw().clone(),
+                moderator_pw: response.moderator_pw().clone(),
+                join_urls: Vec::new(),
+                tracked: false,
+            });
+
+            let join_urls = room
+                .participants
+                .iter()
+                .map(|participant| {
+                    let password = if participant.moderator {
+                        response.moderator_pw()
+                    } else {
+                        response.attendee_pw()
+                    };
+                    let mut params = vec![
+                        ("fullName", participant.full_name.as_str()),
+                        ("meetingID", response.meeting_id().as_str()),
+                        ("password", password.as_str()),
+                    ];
+                    if let Some(user_id) = &participant.user_id {
+                        params.push(("userID", user_id.as_str()));
+                    }
+                    Ok(BreakoutJoinUrl {
+                        participant: participant.clone(),
+                        url: self.generate_url("join", params)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, url::ParseError>>()?;
+            if let Some(created) = session.rooms.last_mut() {
+                created.join_urls = join_urls;
+            }
+        }
+        self.refresh_breakout_rooms(session).await
+    }
+
+    /// Updates which rooms of the session are still reported in the parent meeting's breakout list.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn refresh_breakout_rooms(
+        &self,
+        session: &mut BreakoutSession,
+    ) -> Result<(), anyhow::Error> {
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some(session.parent_meeting_id.clone());
+        let parent = self.get_meeting_info(&request).await?;
+        for room in session.rooms.iter_mut() {
+            room.tracked = parent.breakout_rooms().contains(&room.internal_meeting_id);
+        }
+        Ok(())
+    }
+
+    /// Ends every breakout room of the session.
+    ///
+    /// All rooms are attempted even if ending one of them fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn end_breakout_rooms(&self, session: &BreakoutSession) -> Result<(), anyhow::Error> {
+        let mut failed = Vec::new();
+        for room in &session.rooms {
+            let request = EndMeetingRequest::new(&room.meeting_id, &room.moderator_pw);
+            if let Err(error) = self.end_meeting(&request).await {
+                failed.push(format!("{}: {}", room.meeting_id, error));
+            }
+        }
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "unable to end breakout rooms: {}",
+                failed.join(", ")
+            ))
+        }
+    }
+}
+
+/// Builds the create request of every room and validates them before any room is created
+fn room_requests(
+    plan: &BreakoutPlan,
+    parent_internal_meeting_id: &str,
+) -> Result<Vec<CreateMeetingRequest>, ValidationError> {
+    let mut requests = Vec::with_capacity(plan.rooms.len());
+    let mut violations = Vec::new();
+    for (index, room) in plan.rooms.iter().enumerate() {
+        let sequence = index as u64 + 1;
+        let mut request =
+            CreateMeetingRequest::new(format!("{}-breakout-{}", plan.parent_meeting_id, sequence));
+        request.set_name(Some(room.name.clone()));
+        request.set_is_breakout(Some(true));
+        request.set_parent_meeting_id(Some(parent_internal_meeting_id.to_string()));
+        request.set_sequence(Some(sequence));
+        request.set_free_join(plan.free_join);
+        request.set_duration(plan.duration);
+        request.set_record(plan.record);
+
+        if let Err(error) = request.validate() {
+            violations.extend(error.violations.into_iter().filter(|violation| {
+                !matches!(violation, Violation::InvalidLength { field: "name", .. })
+            }));
+        }
+        requests.push(request);
+    }
+    if violations.is_empty() {
+        Ok(requests)
+    } else {
+        Err(ValidationError { violations })
+    }
+}
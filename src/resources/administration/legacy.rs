@@ -0,0 +1,86 @@
+use crate::error::{BBBError, ResponseCode};
+use crate::Bigbluebutton;
+use getset::{Getters, MutGetters, Setters};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Default, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Stores a custom Flash client configuration for a meeting (removed in 2.3)
+pub struct SetConfigXmlRequest {
+    /// The meeting ID of the running meeting
+    meeting_id: String,
+
+    /// The client configuration, usually the default config.xml with changes
+    config_xml: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Response return from [SetConfigXmlRequest]
+pub struct SetConfigXmlResponse {
+    #[serde(rename = "returncode")]
+    /// Return code
+    return_code: ResponseCode,
+
+    /// Token to pass as `configToken` when joining, see [crate::administration::JoinMeetingRequest]
+    config_token: String,
+}
+
+impl SetConfigXmlRequest {
+    /// Creates new SetConfigXmlRequest
+    pub fn new(meeting_id: impl ToString, config_xml: impl ToString) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            config_xml: config_xml.to_string(),
+        }
+    }
+}
+
+impl Bigbluebutton {
+    /// Returns the default config.xml of the Flash client (removed in 2.3)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_default_config_xml(&self) -> Result<String, anyhow::Error> {
+        let config_xml = self
+            .request_raw("getDefaultConfigXML", Vec::<(&str, &str)>::new())
+            .await?;
+        match serde_xml_rs::from_str::<BBBError>(&config_xml) {
+            Ok(error) if error.return_code == ResponseCode::FAILED => Err(error.into()),
+            _ => Ok(config_xml),
+        }
+    }
+
+    /// Stores a client configuration for a meeting and returns its config token (removed in 2.3).
+    ///
+    /// The XML is POSTed url-encoded, with the checksum computed over `configXML` and
+    /// `meetingID` in that order.
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::legacy::SetConfigXmlRequest;
+    /// use bigbluebutton::administration::JoinMeetingRequest;
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let config_xml = client.get_default_config_xml().await?;
+    /// let config_xml = config_xml.replace("autoJoin=\"true\"", "autoJoin=\"false\"");
+    /// let response = client
+    ///     .set_config_xml(&SetConfigXmlRequest::new("12", config_xml))
+    ///     .await?;
+    ///
+    /// let mut request = JoinMeetingRequest::new("12", "Alice");
+    /// request.set_config_token(Some(response.config_token().clone()));
+    /// let url = client.join_url(&request)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn set_config_xml(
+        &self,
+        req: &SetConfigXmlRequest,
+    ) -> Result<SetConfigXmlResponse, anyhow::Error> {
+        let query = Self::encode_query(vec![
+            ("configXML", req.config_xml.as_str()),
+            ("meetingID", req.meeting_id.as_str()),
+        ]);
+        self.dispatch_form("setConfigXML", &query).await
+    }
+}
@@ -0,0 +1,190 @@
+use super::{EndMeetingRequest, SendChatMessageRequest};
+use crate::monitoring::{GetMeetingsRequest, Meeting};
+use crate::probe::Capabilities;
+use crate::Bigbluebutton;
+use futures::StreamExt;
+use getset::{Getters, MutGetters, Setters};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Describes how [Bigbluebutton::end_all_meetings] winds down a server
+pub struct MaintenancePlan {
+    /// Chat message posted to every meeting before the grace period
+    warning: Option<String>,
+
+    /// Time between the warning and ending the meetings
+    grace_period: Duration,
+
+    /// Maximum number of meetings warned or ended at the same time
+    concurrency: usize,
+
+    /// Only reports the meetings which would be ended
+    dry_run: bool,
+}
+
+impl Default for MaintenancePlan {
+    fn default() -> Self {
+        Self {
+            warning: None,
+            grace_period: Duration::ZERO,
+            concurrency: 4,
+            dry_run: false,
+        }
+    }
+}
+
+impl MaintenancePlan {
+    /// Creates new MaintenancePlan ending every meeting right away, four at a time
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::administration::maintenance::MaintenancePlan;
+    /// use std::time::Duration;
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let mut plan = MaintenancePlan::new();
+    /// plan.set_warning(Some("The server restarts in 5 minutes".to_string()));
+    /// plan.set_grace_period(Duration::from_secs(300));
+    /// let report = client.end_all_meetings(&plan).await?;
+    /// for meeting in report.failed() {
+    ///     println!("{}: {:?}", meeting.meeting_id(), meeting.outcome());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// What happened to a meeting during [Bigbluebutton::end_all_meetings]
+pub enum EndOutcome {
+    /// The meeting has been ended
+    Ended,
+
+    /// Dry run, the meeting would have been ended
+    WouldEnd,
+
+    /// Ending the meeting failed
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+/// Result of ending a single meeting
+pub struct MeetingEndReport {
+    /// Meeting ID
+    meeting_id: String,
+
+    /// Meeting name
+    meeting_name: String,
+
+    /// Participants in the meeting when it was listed
+    participant_count: String,
+
+    /// Whether the warning has been posted to the meeting chat
+    warned: bool,
+
+    /// Outcome of the end call
+    outcome: EndOutcome,
+}
+
+#[derive(Debug, Clone, Default, Getters)]
+#[getset(get = "pub")]
+/// Per-meeting results of [Bigbluebutton::end_all_meetings]
+pub struct MaintenanceReport {
+    /// Reports in the order of the getMeetings response
+    meetings: Vec<MeetingEndReport>,
+}
+
+impl MaintenanceReport {
+    /// Meetings which could not be ended
+    pub fn failed(&self) -> impl Iterator<Item = &MeetingEndReport> {
+        self.meetings
+            .iter()
+            .filter(|meeting| matches!(meeting.outcome, EndOutcome::Failed(_)))
+    }
+}
+
+impl Bigbluebutton {
+    /// Ends every running meeting, e.g. before server maintenance.
+    ///
+    /// The warning is posted with `sendChatMessage` unless the known server version (see
+    /// [Bigbluebutton::server_version]) predates 2.7. Meetings are ended with the moderator
+    /// password reported by getMeetings. Failures are recorded per meeting, only a failing
+    /// getMeetings call is returned as an error.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn end_all_meetings(
+        &self,
+        plan: &MaintenancePlan,
+    ) -> Result<MaintenanceReport, anyhow::Error> {
+        let meetings = self
+            .get_meetings(&GetMeetingsRequest::new())
+            .await?
+            .meetings()
+            .clone();
+        let concurrency = plan.concurrency.max(1);
+
+        let warning = match &plan.warning {
+            Some(warning) if !plan.dry_run && self.supports_chat_warning() => Some(warning),
+            _ => None,
+        };
+        let warned = match warning {
+            Some(warning) => {
+                futures::stream::iter(&meetings)
+                    .map(|meeting| self.post_warning(meeting, warning))
+                    .buffered(concurrency)
+                    .collect::<Vec<_>>()
+                    .await
+            }
+            None => vec![false; meetings.len()],
+        };
+
+        if !plan.dry_run && !meetings.is_empty() && !plan.grace_period.is_zero() {
+            tokio::time::sleep(plan.grace_period).await;
+        }
+
+        let outcomes = futures::stream::iter(&meetings)
+            .map(|meeting| async move {
+                if plan.dry_run {
+                    return EndOutcome::WouldEnd;
+                }
+                let request = EndMeetingRequest::new(meeting.meeting_id(), meeting.moderator_pw());
+                match self.end_meeting(&request).await {
+                    Ok(_) => EndOutcome::Ended,
+                    Err(error) => EndOutcome::Failed(error.to_string()),
+                }
+            })
+            .buffered(concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        Ok(MaintenanceReport {
+            meetings: meetings
+                .iter()
+                .zip(warned)
+                .zip(outcomes)
+                .map(|((meeting, warned), outcome)| MeetingEndReport {
+                    meeting_id: meeting.meeting_id().clone(),
+                    meeting_name: meeting.meeting_name().clone(),
+                    participant_count: meeting.participant_count().clone(),
+                    warned,
+                    outcome,
+                })
+                .collect(),
+        })
+    }
+
+    fn supports_chat_warning(&self) -> bool {
+        match self.server_version() {
+            Some(version) => Capabilities::for_version(&version).send_chat_message,
+            None => true,
+        }
+    }
+
+    async fn post_warning(&self, meeting: &Meeting, message: &str) -> bool {
+        let request = SendChatMessageRequest::new(meeting.meeting_id(), message);
+        self.send_chat_message(&request).await.is_ok()
+    }
+}
@@ -0,0 +1,303 @@
+use getset::{Getters, MutGetters, Setters};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default, PartialEq, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Client settings overridden for a single meeting, sent as `clientSettingsOverride` on create (2.7+).
+///
+/// Each field maps to a key of the client's `settings.yml`, other keys can be set with
+/// [ClientSettingsOverride::set].
+///
+/// ```rust
+/// use bigbluebutton::administration::settings::ClientSettingsOverride;
+/// let mut settings = ClientSettingsOverride::new().set("public.chat.enabled", false);
+/// settings.set_app_name(Some("Math 101".to_string()));
+/// assert_eq!(
+///     settings.to_json(),
+///     r#"{"public":{"app":{"appName":"Math 101"},"chat":{"enabled":false}}}"#
+/// );
+/// ```
+pub struct ClientSettingsOverride {
+    /// Name shown in the browser title, `public.app.appName`
+    app_name: Option<String>,
+
+    /// Asks users for feedback when they leave, `public.app.askForFeedbackOnLogout`
+    ask_for_feedback_on_logout: Option<bool>,
+
+    /// Allows joining listen only, `public.app.listenOnlyMode`
+    listen_only_mode: Option<bool>,
+
+    /// Skips the echo test, `public.app.skipCheck`
+    skip_check: Option<bool>,
+
+    /// Skips the echo test on the first join only, `public.app.skipCheckOnJoin`
+    skip_check_on_join: Option<bool>,
+
+    /// Locale forced for every user, `public.app.defaultSettings.application.overrideLocale`
+    override_locale: Option<String>,
+
+    /// Shares the webcam right after joining, `public.kurento.autoShareWebcam`
+    auto_share_webcam: Option<bool>,
+
+    /// Skips the webcam preview, `public.kurento.skipVideoPreview`
+    skip_video_preview: Option<bool>,
+
+    /// Other settings keyed by their dotted path
+    #[getset(skip)]
+    custom: BTreeMap<String, serde_json::Value>,
+}
+
+impl ClientSettingsOverride {
+    /// Creates new empty ClientSettingsOverride
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides any setting by its dotted path in `settings.yml`, e.g. `public.chat.enabled`
+    pub fn set(mut self, path: impl ToString, value: impl Serialize) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+        self.custom.insert(path.to_string(), value);
+        self
+    }
+
+    /// Nested JSON document expected by bbb-web
+    pub fn to_json(&self) -> String {
+        let typed = [
+            ("public.app.appName", to_value(&self.app_name)),
+            (
+                "public.app.askForFeedbackOnLogout",
+                to_value(&self.ask_for_feedback_on_logout),
+            ),
+            (
+                "public.app.listenOnlyMode",
+                to_value(&self.listen_only_mode),
+            ),
+            ("public.app.skipCheck", to_value(&self.skip_check)),
+            (
+                "public.app.skipCheckOnJoin",
+                to_value(&self.skip_check_on_join),
+            ),
+            (
+                "public.app.defaultSettings.application.overrideLocale",
+                to_value(&self.override_locale),
+            ),
+            (
+                "public.kurento.autoShareWebcam",
+                to_value(&self.auto_share_webcam),
+            ),
+            (
+                "public.kurento.skipVideoPreview",
+                to_value(&self.skip_video_preview),
+            ),
+        ];
+
+        let mut document = serde_json::Map::new();
+        let values = typed
+            .into_iter()
+            .filter_map(|(path, value)| Some((path, value?)))
+            .chain(
+                self.custom
+                    .iter()
+                    .map(|(path, value)| (path.as_str(), value.clone())),
+            );
+        for (path, value) in values {
+            insert_path(&mut document, path, value);
+        }
+        serde_json::Value::Object(document).to_string()
+    }
+}
+
+fn to_value<T: Serialize>(value: &Option<T>) -> Option<serde_json::Value> {
+    value
+        .as_ref()
+        .and_then(|value| serde_json::to_value(value).ok())
+}
+
+fn insert_path(
+    document: &mut serde_json::Map<String, serde_json::Value>,
+    path: &str,
+    value: serde_json::Value,
+) {
+    match path.split_once('.') {
+        Some((key, rest)) => {
+            let child = document
+                .entry(key)
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            if !child.is_object() {
+                *child = serde_json::Value::Object(serde_json::Map::new());
+            }
+            if let serde_json::Value::Object(child) = child {
+                insert_path(child, rest, value);
+            }
+        }
+        None => {
+            document.insert(path.to_string(), value);
+        }
+    }
+}
+
+/// Serializes [ClientSettingsOverride] as its JSON document into the query string
+pub(crate) fn serialize_override<S>(
+    value: &Option<ClientSettingsOverride>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&value.to_json()),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(
+    Debug, Clone, Default, PartialEq, Serialize, Deserialize, Getters, Setters, MutGetters,
+)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Client behaviour of a single user, sent as `userdata-bbb_*` parameters on join.
+///
+/// ```rust
+/// use bigbluebutton::administration::settings::UserData;
+/// use bigbluebutton::administration::JoinMeetingRequest;
+/// # use bigbluebutton::Bigbluebutton;
+/// let client = Bigbluebutton::new("https://server.com/bigbluebutton/", "secret");
+/// let mut request = JoinMeetingRequest::new("12", "Alice");
+/// let mut user_data = UserData::new().custom("lms_course", "math-101");
+/// user_data.set_bbb_skip_check_audio(Some(true));
+/// request.set_user_data(user_data);
+/// let url = client.join_url(&request).unwrap();
+/// assert!(url.contains("userdata-bbb_skip_check_audio=true&userdata-lms_course=math-101"));
+/// ```
+pub struct UserData {
+    /// Shares the webcam right after joining
+    #[serde(
+        rename = "userdata-bbb_auto_share_webcam",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_auto_share_webcam: Option<bool>,
+
+    /// Skips the echo test
+    #[serde(
+        rename = "userdata-bbb_skip_check_audio",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_skip_check_audio: Option<bool>,
+
+    /// Skips the echo test on the first join only
+    #[serde(
+        rename = "userdata-bbb_skip_check_audio_on_first_join",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_skip_check_audio_on_first_join: Option<bool>,
+
+    /// Joins audio automatically
+    #[serde(
+        rename = "userdata-bbb_auto_join_audio",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_auto_join_audio: Option<bool>,
+
+    /// Offers listen only audio
+    #[serde(
+        rename = "userdata-bbb_listen_only_mode",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_listen_only_mode: Option<bool>,
+
+    /// Joins listen only without asking
+    #[serde(
+        rename = "userdata-bbb_force_listen_only",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_force_listen_only: Option<bool>,
+
+    /// URL of a stylesheet loaded by the client
+    #[serde(
+        rename = "userdata-bbb_custom_style_url",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_custom_style_url: Option<String>,
+
+    /// Inline CSS applied by the client
+    #[serde(
+        rename = "userdata-bbb_custom_style",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_custom_style: Option<String>,
+
+    /// Locale forced for the user, e.g. `de`
+    #[serde(
+        rename = "userdata-bbb_override_default_locale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_override_default_locale: Option<String>,
+
+    /// Hides the presentation when joining
+    #[serde(
+        rename = "userdata-bbb_hide_presentation_on_join",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_hide_presentation_on_join: Option<bool>,
+
+    /// Skips the webcam preview
+    #[serde(
+        rename = "userdata-bbb_skip_video_preview",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_skip_video_preview: Option<bool>,
+
+    /// Mirrors the user's own webcam
+    #[serde(
+        rename = "userdata-bbb_mirror_own_webcam",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_mirror_own_webcam: Option<bool>,
+
+    /// Whether the user's webcam is recorded
+    #[serde(
+        rename = "userdata-bbb_record_video",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_record_video: Option<bool>,
+
+    /// Opens the participant list when joining
+    #[serde(
+        rename = "userdata-bbb_show_participants_on_login",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_show_participants_on_login: Option<bool>,
+
+    /// Opens the public chat when joining
+    #[serde(
+        rename = "userdata-bbb_show_public_chat_on_login",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_show_public_chat_on_login: Option<bool>,
+
+    /// Asks for feedback when leaving
+    #[serde(
+        rename = "userdata-bbb_ask_for_feedback_on_logout",
+        skip_serializing_if = "Option::is_none"
+    )]
+    bbb_ask_for_feedback_on_logout: Option<bool>,
+
+    /// Other `userdata-` parameters, keyed with their prefix
+    #[serde(flatten)]
+    #[getset(skip)]
+    custom: BTreeMap<String, String>,
+}
+
+impl UserData {
+    /// Creates new empty UserData
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `userdata-<name>` parameter, e.g. for custom client plugins
+    pub fn custom(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.custom
+            .insert(format!("userdata-{}", name.to_string()), value.to_string());
+        self
+    }
+}
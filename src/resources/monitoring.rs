@@ -4,6 +4,15 @@ use getset::Getters;
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
 
+/// Learning analytics dashboard data
+pub mod dashboard;
+
+/// Helpers waiting for a meeting to reach a state
+pub mod wait;
+
+/// Meeting events computed by polling getMeetings
+pub mod watch;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 /// This call enables you to simply check on whether or not a meeting is running by looking it up with your meeting ID.
@@ -289,6 +298,14 @@ pub struct GetMeetingInfoResponse {
 
     /// Is breakout
     is_breakout: String,
+
+    /// Internal meeting ids of the breakout rooms created for this meeting
+    #[serde(default, deserialize_with = "from_breakout")]
+    breakout_rooms: Vec<String>,
+
+    /// Token giving access to the learning analytics dashboard (2.4+)
+    #[serde(default)]
+    learning_dashboard_access_token: Option<String>,
 }
 impl GetMeetingInfoRequest {
     /// Creates new GetMeetingsRequest
@@ -316,19 +333,39 @@ where
     }
 }
 
+fn from_breakout<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Debug, Deserialize)]
+    struct BreakoutDetailsK {
+        breakout: Option<Vec<String>>,
+    }
+
+    let temp: BreakoutDetailsK = Deserialize::deserialize(deserializer)?;
+    if let Some(value) = temp.breakout {
+        Ok(value)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
 impl Bigbluebutton {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn is_meeting_running(
         &self,
         req: &IsMeetingRunningRequest,
     ) -> Result<IsMeetingRunningResponse, anyhow::Error> {
         self.dispatch("isMeetingRunning", req).await
     }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_meeting_info(
         &self,
         req: &GetMeetingInfoRequest,
     ) -> Result<GetMeetingInfoResponse, anyhow::Error> {
         self.dispatch("getMeetingInfo", req).await
     }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_meetings(
         &self,
         req: &GetMeetingsRequest,
@@ -0,0 +1,410 @@
+use super::GetMeetingInfoResponse;
+use crate::helper;
+use crate::Bigbluebutton;
+use getset::Getters;
+use serde::de::Deserializer;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+/// Identifies the learning analytics dashboard of a meeting (2.4+).
+///
+/// Keep it after the class, the meeting info is gone once the meeting ended.
+pub struct DashboardAccess {
+    /// Internal meeting ID
+    internal_meeting_id: String,
+
+    /// Value of `learningDashboardAccessToken`
+    access_token: String,
+}
+
+impl DashboardAccess {
+    /// Creates new DashboardAccess
+    pub fn new(internal_meeting_id: impl ToString, access_token: impl ToString) -> Self {
+        Self {
+            internal_meeting_id: internal_meeting_id.to_string(),
+            access_token: access_token.to_string(),
+        }
+    }
+
+    /// Reads the access of a running meeting, `None` when the dashboard is disabled
+    pub fn from_meeting_info(info: &GetMeetingInfoResponse) -> Option<Self> {
+        info.learning_dashboard_access_token()
+            .as_ref()
+            .map(|token| Self::new(info.internal_meeting_id(), token))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Activity data of a meeting as shown by the learning analytics dashboard
+pub struct LearningDashboard {
+    /// Internal meeting ID
+    int_id: String,
+
+    /// Meeting ID
+    ext_id: String,
+
+    /// Meeting name
+    name: String,
+
+    /// Creation time in milliseconds since the epoch
+    #[serde(default)]
+    created_on: u64,
+
+    /// End time in milliseconds since the epoch, 0 while running
+    #[serde(default)]
+    ended_on: u64,
+
+    /// Users keyed by their user key
+    #[serde(default)]
+    users: BTreeMap<String, DashboardUser>,
+
+    /// Polls keyed by poll id
+    #[serde(default)]
+    polls: BTreeMap<String, DashboardPoll>,
+
+    /// Screen sharing periods
+    #[serde(default)]
+    screenshares: Vec<ActivityPeriod>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// A user of the meeting, merging every session of the same person
+pub struct DashboardUser {
+    /// Key grouping the sessions of the user
+    user_key: String,
+
+    /// User ID passed to join
+    #[serde(default)]
+    ext_id: String,
+
+    /// Full name
+    name: String,
+
+    /// Whether the user is a moderator
+    #[serde(default)]
+    is_moderator: bool,
+
+    /// Whether the user joined by phone
+    #[serde(default)]
+    is_dial_in: bool,
+
+    /// Sessions keyed by internal user ID
+    #[serde(default)]
+    int_ids: BTreeMap<String, UserSession>,
+
+    /// Poll answers keyed by poll id
+    #[serde(default, deserialize_with = "from_answers")]
+    answers: BTreeMap<String, Vec<String>>,
+
+    /// Talk activity
+    #[serde(default)]
+    talk: TalkActivity,
+
+    /// Emojis set by the user, including raised hands before 2.7
+    #[serde(default)]
+    emojis: Vec<Emoji>,
+
+    /// Reactions sent by the user (2.7+)
+    #[serde(default)]
+    reactions: Vec<Reaction>,
+
+    /// Times the user raised the hand, in milliseconds since the epoch (2.7+)
+    #[serde(default)]
+    raise_hand: Vec<u64>,
+
+    /// Webcam sharing periods
+    #[serde(default)]
+    webcams: Vec<ActivityPeriod>,
+
+    /// Number of public chat messages
+    #[serde(default)]
+    total_of_messages: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// A single session of a user
+pub struct UserSession {
+    /// Join time in milliseconds since the epoch
+    #[serde(default)]
+    registered_on: u64,
+
+    /// Leave time in milliseconds since the epoch, 0 while online
+    #[serde(default)]
+    left_on: u64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Talk activity of a user
+pub struct TalkActivity {
+    /// Total talk time in milliseconds
+    #[serde(default)]
+    total_time: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Emoji status set by a user
+pub struct Emoji {
+    /// Emoji name, e.g. `raiseHand` or `happy`
+    name: String,
+
+    /// Time in milliseconds since the epoch
+    #[serde(default)]
+    sent_on: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Reaction sent by a user
+pub struct Reaction {
+    /// Reaction emoji
+    reaction: String,
+
+    /// Time in milliseconds since the epoch
+    #[serde(default)]
+    sent_on: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Period of a webcam or screen share
+pub struct ActivityPeriod {
+    /// Start time in milliseconds since the epoch
+    #[serde(default)]
+    started_on: u64,
+
+    /// Stop time in milliseconds since the epoch, 0 while active
+    #[serde(default)]
+    stopped_on: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// A poll of the meeting
+pub struct DashboardPoll {
+    /// Poll id
+    poll_id: String,
+
+    /// Poll type, e.g. `YN` or `CUSTOM`
+    #[serde(rename = "type", default)]
+    poll_type: String,
+
+    /// Question
+    #[serde(default)]
+    question: String,
+
+    /// Answer options
+    #[serde(default)]
+    options: Vec<String>,
+
+    /// Whether answers are anonymous
+    #[serde(default)]
+    anonymous: bool,
+
+    /// Creation time in milliseconds since the epoch
+    #[serde(default)]
+    created_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+/// Attendance and engagement of a user, computed by [LearningDashboard::summaries]
+pub struct UserSummary {
+    /// Key grouping the sessions of the user
+    user_key: String,
+
+    /// Full name
+    name: String,
+
+    /// Whether the user is a moderator
+    moderator: bool,
+
+    /// Time spent in the meeting over every session
+    online_time: Duration,
+
+    /// Talk time
+    talk_time: Duration,
+
+    /// Time with a shared webcam
+    webcam_time: Duration,
+
+    /// Public chat messages
+    messages: u64,
+
+    /// Raised hands
+    raised_hands: usize,
+
+    /// Emojis and reactions, without raised hands
+    emojis: usize,
+
+    /// Answered polls
+    poll_answers: usize,
+}
+
+impl LearningDashboard {
+    /// Summaries of every user, sorted by name
+    pub fn summaries(&self) -> Vec<UserSummary> {
+        let mut summaries = self
+            .users
+            .values()
+            .map(|user| self.summary(user))
+            .collect::<Vec<_>>();
+        summaries.sort_by(|a, b| a.name.cmp(&b.name));
+        summaries
+    }
+
+    /// Summary of a single user
+    pub fn summary(&self, user: &DashboardUser) -> UserSummary {
+        let online = user
+            .int_ids
+            .values()
+            .map(|session| self.elapsed(session.registered_on, session.left_on))
+            .sum();
+        let webcam = user
+            .webcams
+            .iter()
+            .map(|period| self.elapsed(period.started_on, period.stopped_on))
+            .sum();
+        let raised_emojis = user
+            .emojis
+            .iter()
+            .filter(|emoji| emoji.name == "raiseHand")
+            .count();
+
+        UserSummary {
+            user_key: user.user_key.clone(),
+            name: user.name.clone(),
+            moderator: user.is_moderator,
+            online_time: Duration::from_millis(online),
+            talk_time: Duration::from_millis(user.talk.total_time),
+            webcam_time: Duration::from_millis(webcam),
+            messages: user.total_of_messages,
+            raised_hands: user.raise_hand.len() + raised_emojis,
+            emojis: user.emojis.len() - raised_emojis + user.reactions.len(),
+            poll_answers: user.answers.len(),
+        }
+    }
+
+    /// Milliseconds between two times, open periods end with the meeting or now
+    fn elapsed(&self, started_on: u64, stopped_on: u64) -> u64 {
+        let stopped_on = match (stopped_on, self.ended_on) {
+            (0, 0) => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |now| now.as_millis() as u64),
+            (0, ended_on) => ended_on,
+            (stopped_on, _) => stopped_on,
+        };
+        stopped_on.saturating_sub(started_on)
+    }
+}
+
+fn from_answers<'de, D>(deserializer: D) -> Result<BTreeMap<String, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum AnswerK {
+        Single(String),
+        Multiple(Vec<String>),
+    }
+
+    let answers = BTreeMap::<String, AnswerK>::deserialize(deserializer)?;
+    Ok(answers
+        .into_iter()
+        .map(|(poll_id, answer)| match answer {
+            AnswerK::Single(answer) => (poll_id, vec![answer]),
+            AnswerK::Multiple(answers) => (poll_id, answers),
+        })
+        .collect())
+}
+
+impl Bigbluebutton {
+    /// URL of the learning analytics dashboard page of a meeting
+    ///
+    /// ```rust
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::monitoring::dashboard::DashboardAccess;
+    /// let client = Bigbluebutton::new("https://server.com/bigbluebutton/", "secret");
+    /// let access = DashboardAccess::new("abc-123", "token");
+    /// assert_eq!(
+    ///     client.learning_dashboard_url(&access).unwrap(),
+    ///     "https://server.com/learning-analytics-dashboard/?meeting=abc-123&report=token"
+    /// );
+    /// ```
+    pub fn learning_dashboard_url(
+        &self,
+        access: &DashboardAccess,
+    ) -> Result<String, url::ParseError> {
+        Ok(format!(
+            "{}?{}",
+            self.learning_dashboard_root()?,
+            helper::encode_query(vec![
+                ("meeting", access.internal_meeting_id.as_str()),
+                ("report", access.access_token.as_str()),
+            ])
+        ))
+    }
+
+    /// URL of the activity JSON behind the dashboard page
+    pub fn learning_dashboard_data_url(
+        &self,
+        access: &DashboardAccess,
+    ) -> Result<String, url::ParseError> {
+        Ok(format!(
+            "{}{}/{}/learning_dashboard_data.json",
+            self.learning_dashboard_root()?,
+            helper::encode_component(&access.internal_meeting_id),
+            helper::encode_component(&access.access_token)
+        ))
+    }
+
+    /// Downloads the activity data of a meeting
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::monitoring::dashboard::DashboardAccess;
+    /// use bigbluebutton::monitoring::GetMeetingInfoRequest;
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let mut request = GetMeetingInfoRequest::new();
+    /// request.meeting_id = Some("12".to_string());
+    /// let info = client.get_meeting_info(&request).await?;
+    /// let access = DashboardAccess::from_meeting_info(&info).expect("dashboard is disabled");
+    ///
+    /// let dashboard = client.get_learning_dashboard(&access).await?;
+    /// for user in dashboard.summaries() {
+    ///     println!("{}: {:?} online, {} messages", user.name(), user.online_time(), user.messages());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_learning_dashboard(
+        &self,
+        access: &DashboardAccess,
+    ) -> Result<LearningDashboard, anyhow::Error> {
+        let url = self.learning_dashboard_data_url(access)?;
+        let text_response = self.send("learningDashboard", &url).await?;
+        Ok(serde_json::from_str(&text_response)?)
+    }
+
+    /// Dashboard root next to the API, e.g. `https://server.com/learning-analytics-dashboard/`
+    fn learning_dashboard_root(&self) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(&self.url)?.join("/learning-analytics-dashboard/")
+    }
+}
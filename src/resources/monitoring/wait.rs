@@ -0,0 +1,141 @@
+use super::{GetMeetingInfoRequest, GetMeetingInfoResponse, IsMeetingRunningRequest};
+use crate::error::{BBBError, WaitError};
+use crate::Bigbluebutton;
+use getset::{Getters, MutGetters, Setters};
+use std::future::Future;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Debug, Clone, Getters, Setters, MutGetters)]
+#[getset(get = "pub", set = "pub", get_mut = "pub")]
+/// Controls how the wait helpers poll the server
+pub struct WaitOptions {
+    /// Delay between two polls (default 5 seconds)
+    interval: Duration,
+
+    /// Maximum time to wait, waits forever if not set
+    timeout: Option<Duration>,
+
+    /// Token which stops the wait when cancelled
+    cancellation: Option<CancellationToken>,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            timeout: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl WaitOptions {
+    /// Creates new WaitOptions
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::monitoring::wait::WaitOptions;
+    /// use bigbluebutton::monitoring::IsMeetingRunningRequest;
+    /// use std::time::Duration;
+    /// # async fn run(client: Bigbluebutton) -> anyhow::Result<()> {
+    /// let mut options = WaitOptions::new();
+    /// options.set_interval(Duration::from_secs(2));
+    /// options.set_timeout(Some(Duration::from_secs(300)));
+    /// client
+    ///     .wait_for_running(&IsMeetingRunningRequest::new("12"), &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Bigbluebutton {
+    /// Waits until the meeting is running, i.e. a user has joined it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn wait_for_running(
+        &self,
+        req: &IsMeetingRunningRequest,
+        options: &WaitOptions,
+    ) -> Result<(), anyhow::Error> {
+        self.poll_until(options, || async {
+            let response = self.is_meeting_running(req).await?;
+            Ok(response.running.then_some(()))
+        })
+        .await
+    }
+
+    /// Waits until the meeting is no longer running.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn wait_for_end(
+        &self,
+        req: &IsMeetingRunningRequest,
+        options: &WaitOptions,
+    ) -> Result<(), anyhow::Error> {
+        self.poll_until(options, || async {
+            let response = self.is_meeting_running(req).await?;
+            Ok((!response.running).then_some(()))
+        })
+        .await
+    }
+
+    /// Waits until at least `participants` users are in the meeting.
+    ///
+    /// A meeting which does not exist yet is polled again instead of failing.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn wait_for_participants(
+        &self,
+        req: &GetMeetingInfoRequest,
+        participants: u64,
+        options: &WaitOptions,
+    ) -> Result<GetMeetingInfoResponse, anyhow::Error> {
+        self.poll_until(options, || async {
+            let response = match self.get_meeting_info(req).await {
+                Ok(response) => response,
+                Err(error) => match error.downcast_ref::<BBBError>() {
+                    Some(error) if error.message_key == "notFound" => return Ok(None),
+                    _ => return Err(error),
+                },
+            };
+            let count = response.participant_count().parse::<u64>().unwrap_or(0);
+            Ok((count >= participants).then_some(response))
+        })
+        .await
+    }
+
+    async fn poll_until<T, F, Fut>(&self, options: &WaitOptions, mut check: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = anyhow::Result<Option<T>>>,
+    {
+        let polling = async {
+            loop {
+                if let Some(value) = check().await? {
+                    return Ok(value);
+                }
+                tokio::time::sleep(options.interval).await;
+            }
+        };
+        let timeout = async {
+            match options.timeout {
+                Some(timeout) => tokio::time::sleep(timeout).await,
+                None => std::future::pending().await,
+            }
+        };
+        let cancelled = async {
+            match &options.cancellation {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            result = polling => result,
+            _ = timeout => Err(WaitError::Timeout.into()),
+            _ = cancelled => Err(WaitError::Cancelled.into()),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use super::{Attendee, GetMeetingsRequest, Meeting};
+use crate::Bigbluebutton;
+use futures::Stream;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Change detected between two consecutive `getMeetings` snapshots
+pub enum MeetingEvent {
+    /// A meeting appeared on the server
+    MeetingStarted {
+        /// Meeting Id
+        meeting_id: String,
+        /// Internal Meeting Id
+        internal_meeting_id: String,
+        /// Meeting name
+        meeting_name: String,
+    },
+
+    /// A meeting disappeared from the server
+    MeetingEnded {
+        /// Meeting Id
+        meeting_id: String,
+        /// Internal Meeting Id
+        internal_meeting_id: String,
+    },
+
+    /// A user joined a meeting
+    UserJoined {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+        /// Full name of user
+        full_name: String,
+        /// User role
+        role: String,
+    },
+
+    /// A user left a meeting
+    UserLeft {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+        /// Full name of user
+        full_name: String,
+    },
+
+    /// The presenter of a meeting changed
+    PresenterChanged {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id of the new presenter, if any
+        user_id: Option<String>,
+    },
+
+    /// A user started sharing video
+    VideoStarted {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+    },
+
+    /// A user stopped sharing video
+    VideoStopped {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+    },
+
+    /// A user joined the audio conference
+    VoiceJoined {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+    },
+
+    /// A user left the audio conference
+    VoiceLeft {
+        /// Meeting Id
+        meeting_id: String,
+        /// User Id
+        user_id: String,
+    },
+}
+
+#[derive(Debug, Clone, Default)]
+/// Computes [MeetingEvent]s from consecutive `getMeetings` snapshots.
+///
+/// Meetings are identified by their internal meeting ID and attendees by their user ID.
+/// The first snapshot only establishes the baseline and produces no events.
+pub struct MeetingWatcher {
+    meetings: Option<Vec<Meeting>>,
+}
+
+impl MeetingWatcher {
+    /// Creates new MeetingWatcher
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a new snapshot and returns the events since the previous one.
+    pub fn update(&mut self, meetings: Vec<Meeting>) -> Vec<MeetingEvent> {
+        let previous = match self.meetings.replace(meetings) {
+            Some(previous) => previous,
+            None => return Vec::new(),
+        };
+        let current = self.meetings.as_deref().unwrap_or_default();
+
+        let previous_by_id: HashMap<&str, &Meeting> = previous
+            .iter()
+            .map(|meeting| (meeting.internal_meeting_id.as_str(), meeting))
+            .collect();
+        let current_by_id: HashMap<&str, &Meeting> = current
+            .iter()
+            .map(|meeting| (meeting.internal_meeting_id.as_str(), meeting))
+            .collect();
+
+        let mut events = Vec::new();
+        for meeting in &previous {
+            if !current_by_id.contains_key(meeting.internal_meeting_id.as_str()) {
+                events.push(MeetingEvent::MeetingEnded {
+                    meeting_id: meeting.meeting_id.clone(),
+                    internal_meeting_id: meeting.internal_meeting_id.clone(),
+                });
+            }
+        }
+        for meeting in current {
+            let before = previous_by_id.get(meeting.internal_meeting_id.as_str());
+            if before.is_none() {
+                events.push(MeetingEvent::MeetingStarted {
+                    meeting_id: meeting.meeting_id.clone(),
+                    internal_meeting_id: meeting.internal_meeting_id.clone(),
+                    meeting_name: meeting.meeting_name.clone(),
+                });
+            }
+            diff_attendees(
+                &mut events,
+                &meeting.meeting_id,
+                before
+                    .map(|meeting| meeting.attendees.as_slice())
+                    .unwrap_or_default(),
+                &meeting.attendees,
+            );
+        }
+        events
+    }
+}
+
+fn diff_attendees(
+    events: &mut Vec<MeetingEvent>,
+    meeting_id: &str,
+    previous: &[Attendee],
+    current: &[Attendee],
+) {
+    let previous_by_id: HashMap<&str, &Attendee> = previous
+        .iter()
+        .map(|attendee| (attendee.user_id.as_str(), attendee))
+        .collect();
+    let current_by_id: HashMap<&str, &Attendee> = current
+        .iter()
+        .map(|attendee| (attendee.user_id.as_str(), attendee))
+        .collect();
+
+    for attendee in previous {
+        if !current_by_id.contains_key(attendee.user_id.as_str()) {
+            events.push(MeetingEvent::UserLeft {
+                meeting_id: meeting_id.to_string(),
+                user_id: attendee.user_id.clone(),
+                full_name: attendee.full_name.clone(),
+            });
+        }
+    }
+
+    for attendee in current {
+        let before = previous_by_id.get(attendee.user_id.as_str());
+        if before.is_none() {
+            events.push(MeetingEvent::UserJoined {
+                meeting_id: meeting_id.to_string(),
+                user_id: attendee.user_id.clone(),
+                full_name: attendee.full_name.clone(),
+                role: attendee.role.clone(),
+            });
+        }
+
+        let had_video = before.map(|before| before.has_video).unwrap_or(false);
+        if attendee.has_video != had_video {
+            let meeting_id = meeting_id.to_string();
+            let user_id = attendee.user_id.clone();
+            events.push(if attendee.has_video {
+                MeetingEvent::VideoStarted {
+                    meeting_id,
+                    user_id,
+                }
+            } else {
+                MeetingEvent::VideoStopped {
+                    meeting_id,
+                    user_id,
+                }
+            });
+        }
+
+        let had_voice = before
+            .map(|before| before.has_joined_voice)
+            .unwrap_or(false);
+        if attendee.has_joined_voice != had_voice {
+            let meeting_id = meeting_id.to_string();
+            let user_id = attendee.user_id.clone();
+            events.push(if attendee.has_joined_voice {
+                MeetingEvent::VoiceJoined {
+                    meeting_id,
+                    user_id,
+                }
+            } else {
+                MeetingEvent::VoiceLeft {
+                    meeting_id,
+                    user_id,
+                }
+            });
+        }
+    }
+
+    let previous_presenter = presenter(previous);
+    let current_presenter = presenter(current);
+    if previous_presenter != current_presenter {
+        events.push(MeetingEvent::PresenterChanged {
+            meeting_id: meeting_id.to_string(),
+            user_id: current_presenter.map(ToString::to_string),
+        });
+    }
+}
+
+fn presenter(attendees: &[Attendee]) -> Option<&str> {
+    attendees
+        .iter()
+        .find(|attendee| attendee.is_presenter == "true")
+        .map(|attendee| attendee.user_id.as_str())
+}
+
+impl Bigbluebutton {
+    /// Polls `getMeetings` at the given interval and yields the changes between snapshots.
+    ///
+    /// Errors are yielded as they happen and polling continues on the next tick.
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    /// # async fn run(client: Bigbluebutton) {
+    /// let events = client.watch_meetings(Duration::from_secs(10));
+    /// futures::pin_mut!(events);
+    /// while let Some(event) = events.next().await {
+    ///     println!("{:?}", event);
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_meetings(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<MeetingEvent, anyhow::Error>> {
+        struct WatchState {
+            client: Bigbluebutton,
+            watcher: MeetingWatcher,
+            pending: VecDeque<MeetingEvent>,
+            polled: bool,
+        }
+
+        let state = WatchState {
+            client: self.clone(),
+            watcher: MeetingWatcher::new(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+        futures::stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+                if state.polled {
+                    tokio::time::sleep(interval).await;
+                }
+                state.polled = true;
+                match state.client.get_meetings(&GetMeetingsRequest::new()).await {
+                    Ok(response) => {
+                        let events = state.watcher.update(response.meetings().clone());
+                        state.pending.extend(events);
+                    }
+                    Err(error) => return Some((Err(error), state)),
+                }
+            }
+        })
+    }
+}
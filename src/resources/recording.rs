@@ -1,7 +1,9 @@
 use crate::error::ResponseCode;
 use crate::Bigbluebutton;
 use getset::Getters;
+use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -48,7 +50,6 @@ impl PublishRecordingsRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 ///Delete one or more recordings for a given recordID (or set of record IDs).
-
 pub struct DeleteRecordingsRequest {
     #[serde(rename = "recordID")]
     /// A record ID for specify the recordings to delete. It can be a set of record IDs separated by commas.
@@ -84,13 +85,219 @@ impl DeleteRecordingsRequest {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+/// Retrieves the recordings that are available for playback for a given meetingID (or set of meeting IDs).
+pub struct GetRecordingsRequest {
+    #[serde(rename = "meetingID")]
+    /// A meeting ID for get the recordings. It can be a set of meetingIDs separate by commas.
+    pub meeting_id: Option<String>,
+
+    #[serde(rename = "recordID")]
+    /// A record ID for get the recordings. It can be a set of recordIDs separate by commas.
+    pub record_id: Option<String>,
+
+    /// Filters recordings by state, e.g. published,unpublished. It can be a set of states separate by commas.
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Response return from [GetRecordingsRequest]
+pub struct GetRecordingsResponse {
+    #[serde(rename = "returncode")]
+    /// return code
+    return_code: ResponseCode,
+
+    /// Recordings
+    #[serde(default, deserialize_with = "from_recording")]
+    recordings: Vec<Recording>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Recording details
+pub struct Recording {
+    #[serde(rename = "recordID")]
+    /// Record Id
+    record_id: String,
+
+    #[serde(rename = "meetingID")]
+    /// Meeting Id
+    meeting_id: String,
+
+    #[serde(rename = "internalMeetingID")]
+    /// Internal Meeting Id
+    internal_meeting_id: Option<String>,
+
+    /// Meeting name
+    name: String,
+
+    /// Whether the recording is published
+    published: bool,
+
+    /// Recording state, e.g. published, unpublished or deleted
+    state: Option<String>,
+
+    /// Start time
+    start_time: String,
+
+    /// End time
+    end_time: String,
+
+    /// Number of participants
+    participants: Option<String>,
+
+    #[serde(default)]
+    /// Recording metadata, e.g. `meetingName` or values passed as `meta_` on create
+    metadata: BTreeMap<String, String>,
+
+    #[serde(default, deserialize_with = "from_playback")]
+    /// Playback formats of the recording
+    playback: Vec<PlaybackFormat>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Playback format of a recording
+pub struct PlaybackFormat {
+    #[serde(rename = "type")]
+    /// Format type, e.g. presentation or video
+    format_type: String,
+
+    /// Playback URL
+    url: String,
+
+    /// Processing time in milliseconds
+    processing_time: Option<String>,
+
+    /// Length in minutes
+    length: Option<String>,
+}
+
+impl Recording {
+    /// URL of the first playback format, if the recording has been processed
+    pub fn playback_url(&self) -> Option<&str> {
+        self.playback.first().map(|format| format.url.as_str())
+    }
+}
+
+fn from_playback<'de, D>(deserializer: D) -> Result<Vec<PlaybackFormat>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Debug, Deserialize)]
+    struct PlaybackK {
+        format: Option<Vec<PlaybackFormat>>,
+    }
+
+    let temp: PlaybackK = Deserialize::deserialize(deserializer)?;
+    Ok(temp.format.unwrap_or_default())
+}
+
+fn from_recording<'de, D>(deserializer: D) -> Result<Vec<Recording>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Debug, Deserialize)]
+    struct RecordingDetailsK {
+        recording: Option<Vec<Recording>>,
+    }
+
+    let temp: RecordingDetailsK = Deserialize::deserialize(deserializer)?;
+    if let Some(value) = temp.recording {
+        Ok(value)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+impl GetRecordingsRequest {
+    /// Creates new GetRecordingsRequest
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+/// Updates metadata of one or more recordings for a given recordID (or set of record IDs).
+pub struct UpdateRecordingsRequest {
+    #[serde(rename = "recordID")]
+    /// A record ID for specify the recordings to update. It can be a set of record IDs separated by commas.
+    pub record_id: String,
+
+    #[serde(flatten)]
+    /// Metadata to update, keyed by `meta_` parameter name. An empty value removes the metadata.
+    pub meta: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+/// Response return from [UpdateRecordingsRequest]
+pub struct UpdateRecordingsResponse {
+    #[serde(rename = "returncode")]
+    /// return code
+    return_code: ResponseCode,
+
+    /// whether recording is updated or not
+    updated: bool,
+}
+
+impl UpdateRecordingsRequest {
+    /// Creates new UpdateRecordingsRequest
+    ///
+    /// ```rust,no_run
+    /// # use bigbluebutton::Bigbluebutton;
+    /// use bigbluebutton::recording::UpdateRecordingsRequest;
+    /// let client = Bigbluebutton::new("https://server.com/bigbluebutton/", "secret");
+    /// let request = UpdateRecordingsRequest::new("12").meta("presenter", "Alice");
+    /// client.update_recordings(&request);
+    /// ```
+    pub fn new(record_id: impl ToString) -> Self {
+        Self {
+            record_id: record_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the `meta_<name>` parameter
+    pub fn meta(mut self, name: impl AsRef<str>, value: impl ToString) -> Self {
+        self.meta
+            .insert(format!("meta_{}", name.as_ref()), value.to_string());
+        self
+    }
+}
+
 impl Bigbluebutton {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_recordings(
+        &self,
+        req: &GetRecordingsRequest,
+    ) -> Result<GetRecordingsResponse, anyhow::Error> {
+        self.dispatch("getRecordings", req).await
+    }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn update_recordings(
+        &self,
+        req: &UpdateRecordingsRequest,
+    ) -> Result<UpdateRecordingsResponse, anyhow::Error> {
+        self.dispatch("updateRecordings", req).await
+    }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn delete_recordings(
         &self,
         req: &DeleteRecordingsRequest,
     ) -> Result<DeleteRecordingsResponse, anyhow::Error> {
         self.dispatch("deleteRecordings", req).await
     }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn publish_recordings(
         &self,
         req: &PublishRecordingsRequest,
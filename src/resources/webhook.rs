@@ -189,18 +189,21 @@ impl ListHooksRequest {
 }
 
 impl Bigbluebutton {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_hook(
         &self,
         req: &CreateHookRequest,
     ) -> Result<CreateHookResponse, anyhow::Error> {
         self.dispatch("hooks/create", req).await
     }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_hooks(
         &self,
         req: &ListHooksRequest,
     ) -> Result<ListHooksResponse, anyhow::Error> {
         self.dispatch("hooks/list", req).await
     }
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn destroy_hook(
         &self,
         req: &DestroyHookRequest,
@@ -0,0 +1,134 @@
+use crate::error::HttpError;
+use std::time::Duration;
+
+/// APIs which do not change server state and are always safe to retry
+const READ_ONLY_APIS: &[&str] = &[
+    "getMeetings",
+    "getMeetingInfo",
+    "isMeetingRunning",
+    "hooks/list",
+    "getRecordings",
+];
+
+#[derive(Debug, Clone)]
+/// Retry policy applied by the client when a request fails with a transient error.
+///
+/// Read-only APIs are retried automatically, mutating APIs such as `create` or `end`
+/// are only retried once opted in through [RetryPolicy::retry_mutating].
+///
+/// ```rust
+/// use bigbluebutton::retry::RetryPolicy;
+/// use bigbluebutton::Bigbluebutton;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .initial_backoff(Duration::from_millis(200))
+///     .retry_mutating("end");
+/// let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "secret")
+///     .retry_policy(policy)
+///     .build();
+/// ```
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retryable_statuses: Vec<u16>,
+    retry_connection_errors: bool,
+    mutating_apis: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+            retryable_statuses: vec![429, 502, 503, 504],
+            retry_connection_errors: true,
+            mutating_apis: Vec::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates new RetryPolicy with 3 attempts and exponential backoff starting at 100ms
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total number of attempts, including the first one
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry, doubled after every attempt
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Upper bound of the delay between two attempts
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Randomizes every delay between half and the full backoff (default true)
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// HTTP status codes which are retried (default 429, 502, 503 and 504)
+    pub fn retryable_statuses(mut self, statuses: impl IntoIterator<Item = u16>) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Whether connection errors and timeouts are retried (default true)
+    pub fn retry_connection_errors(mut self, retry: bool) -> Self {
+        self.retry_connection_errors = retry;
+        self
+    }
+
+    /// Opts a mutating API, e.g. `create` or `end`, in to retries
+    pub fn retry_mutating(mut self, api_path: impl ToString) -> Self {
+        self.mutating_apis.push(api_path.to_string());
+        self
+    }
+
+    pub(crate) fn should_retry(&self, api_path: &str, attempt: u32, error: &anyhow::Error) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        if !READ_ONLY_APIS.contains(&api_path)
+            && !self.mutating_apis.iter().any(|api| api == api_path)
+        {
+            return false;
+        }
+        if let Some(error) = error.downcast_ref::<HttpError>() {
+            return self.retryable_statuses.contains(&error.status);
+        }
+        if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+            return self.retry_connection_errors && (error.is_connect() || error.is_timeout());
+        }
+        false
+    }
+
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if self.jitter {
+            backoff.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// BigBlueButton server version, e.g. `2.7.3`
+pub struct ServerVersion {
+    /// Major version
+    pub major: u32,
+    /// Minor version
+    pub minor: u32,
+    /// Patch version
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    /// Creates new ServerVersion
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses versions as reported by `bbbVersion`, e.g. `2.6.10` or `3.0.0-beta.1`
+    ///
+    /// ```rust
+    /// use bigbluebutton::version::ServerVersion;
+    /// assert_eq!(ServerVersion::parse("2.6.10"), Some(ServerVersion::new(2, 6, 10)));
+    /// assert_eq!(ServerVersion::parse("3.0.0-beta.1"), Some(ServerVersion::new(3, 0, 0)));
+    /// assert_eq!(ServerVersion::parse("2.7"), Some(ServerVersion::new(2, 7, 0)));
+    /// assert_eq!(ServerVersion::parse("unknown"), None);
+    /// ```
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches('v');
+        let mut parts = version.split('.').map(|part| {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(part, |end| &part[..end]);
+            digits.parse::<u32>().ok()
+        });
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = parts.next().flatten().unwrap_or(0);
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// What the client does when a request sets a parameter the server version does not support
+pub enum CompatibilityMode {
+    /// Sends the request as is
+    Ignore,
+
+    /// Sends the request and logs a warning through `tracing`, nothing is logged when the
+    /// feature is disabled
+    #[default]
+    Warn,
+
+    /// Sends the request and prints a warning on stderr
+    Print,
+
+    /// Refuses to send the request with [crate::error::UnsupportedParameterError]
+    Error,
+}
+
+/// Parameter applying to the whole API rather than a single field
+const WHOLE_API: &str = "*";
+
+/// First server version accepting an API parameter, `*` marks APIs added after 2.2.
+/// Parameters missing from the table are available since 2.2.
+const PARAMETER_SUPPORT: &[(&str, &str, ServerVersion)] = &[
+    ("create", "endWhenNoModerator", ServerVersion::new(2, 3, 0)),
+    (
+        "create",
+        "endWhenNoModeratorDelayInMinutes",
+        ServerVersion::new(2, 3, 0),
+    ),
+    ("create", "meetingLayout", ServerVersion::new(2, 4, 0)),
+    (
+        "create",
+        "learningDashboardEnabled",
+        ServerVersion::new(2, 4, 0),
+    ),
+    (
+        "create",
+        "learningDashboardCleanupDelayInMinutes",
+        ServerVersion::new(2, 4, 0),
+    ),
+    (
+        "create",
+        "allowModsToEjectCameras",
+        ServerVersion::new(2, 4, 0),
+    ),
+    (
+        "create",
+        "virtualBackgroundsDisabled",
+        ServerVersion::new(2, 4, 0),
+    ),
+    (
+        "create",
+        "breakoutRoomsPrivateChatEnabled",
+        ServerVersion::new(2, 4, 0),
+    ),
+    ("create", "breakoutRoomsRecord", ServerVersion::new(2, 4, 0)),
+    ("create", "meetingCameraCap", ServerVersion::new(2, 4, 0)),
+    ("create", "userCameraCap", ServerVersion::new(2, 4, 0)),
+    (
+        "create",
+        "meetingExpireIfNoUserJoinedInMinutes",
+        ServerVersion::new(2, 4, 0),
+    ),
+    (
+        "create",
+        "meetingExpireWhenLastUserLeftInMinutes",
+        ServerVersion::new(2, 4, 0),
+    ),
+    ("create", "groups", ServerVersion::new(2, 4, 0)),
+    ("create", "disabledFeatures", ServerVersion::new(2, 5, 0)),
+    ("create", "notifyRecordingIsOn", ServerVersion::new(2, 5, 0)),
+    (
+        "create",
+        "preUploadedPresentationOverrideDefault",
+        ServerVersion::new(2, 5, 0),
+    ),
+    (
+        "create",
+        "lockSettingsHideViewersCursor",
+        ServerVersion::new(2, 5, 0),
+    ),
+    (
+        "create",
+        "recordFullDurationMedia",
+        ServerVersion::new(2, 6, 0),
+    ),
+    (
+        "create",
+        "presentationUploadExternalUrl",
+        ServerVersion::new(2, 6, 0),
+    ),
+    (
+        "create",
+        "presentationUploadExternalDescription",
+        ServerVersion::new(2, 6, 0),
+    ),
+    (
+        "create",
+        "preUploadedPresentation",
+        ServerVersion::new(2, 7, 0),
+    ),
+    (
+        "create",
+        "preUploadedPresentationName",
+        ServerVersion::new(2, 7, 0),
+    ),
+    (
+        "create",
+        "disabledFeaturesExclude",
+        ServerVersion::new(2, 7, 0),
+    ),
+    (
+        "create",
+        "clientSettingsOverride",
+        ServerVersion::new(2, 7, 0),
+    ),
+    (
+        "create",
+        "allowOverrideClientSettingsOnCreateCall",
+        ServerVersion::new(3, 0, 0),
+    ),
+    ("create", "loginURL", ServerVersion::new(3, 0, 0)),
+    ("create", "pluginManifests", ServerVersion::new(3, 0, 0)),
+    ("join", "excludeFromDashboard", ServerVersion::new(2, 4, 0)),
+    ("join", "role", ServerVersion::new(2, 6, 0)),
+    ("join", "errorRedirectUrl", ServerVersion::new(2, 7, 0)),
+    ("join", "logoutURL", ServerVersion::new(3, 0, 0)),
+    ("join", "firstName", ServerVersion::new(3, 0, 0)),
+    ("join", "lastName", ServerVersion::new(3, 0, 0)),
+    ("join", "enforceLayout", ServerVersion::new(3, 0, 0)),
+    ("join", "bot", ServerVersion::new(3, 0, 0)),
+    ("join", "webcamBackgroundURL", ServerVersion::new(3, 0, 0)),
+    ("sendChatMessage", WHOLE_API, ServerVersion::new(2, 7, 0)),
+    ("getJoinUrl", WHOLE_API, ServerVersion::new(3, 0, 0)),
+];
+
+/// First server version accepting a parameter of an API, `None` when available since 2.2
+///
+/// ```rust
+/// use bigbluebutton::version::{parameter_since, ServerVersion};
+/// assert_eq!(parameter_since("create", "disabledFeatures"), Some(ServerVersion::new(2, 5, 0)));
+/// assert_eq!(parameter_since("create", "name"), None);
+/// ```
+pub fn parameter_since(api_path: &str, parameter: &str) -> Option<ServerVersion> {
+    PARAMETER_SUPPORT
+        .iter()
+        .find(|(api, name, _)| *api == api_path && *name == parameter)
+        .map(|(_, _, since)| *since)
+}
+
+/// Parameters of a serialized query which `version` does not support, with the version
+/// adding them. An API added after `version` is reported as parameter `*`.
+pub(crate) fn unsupported_parameters(
+    api_path: &str,
+    query: &str,
+    version: &ServerVersion,
+) -> Vec<(String, ServerVersion)> {
+    let mut unsupported = Vec::new();
+    if let Some(since) = parameter_since(api_path, WHOLE_API) {
+        if *version < since {
+            unsupported.push((WHOLE_API.to_string(), since));
+        }
+    }
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let name = pair.split_once('=').map_or(pair, |(name, _)| name);
+        if let Some(since) = parameter_since(api_path, name) {
+            if *version < since {
+                unsupported.push((name.to_string(), since));
+            }
+        }
+    }
+    unsupported
+}
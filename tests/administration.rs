@@ -1,17 +1,23 @@
+mod common;
+
 #[cfg(test)]
 mod test {
-    use bigbluebutton::administration::{CreateMeetingRequest, EndMeetingRequest};
+    use super::common::{
+        api_name, create_response, end_response, failed, meeting_info_response, MockServer,
+    };
+    use bigbluebutton::administration::{
+        CreateMeetingRequest, EndMeetingRequest, EnsureMeetingOutcome, JoinMeetingRequest,
+    };
+    use bigbluebutton::error::{UnsupportedParameterError, Violation};
+    use bigbluebutton::version::{CompatibilityMode, ServerVersion};
     use bigbluebutton::Bigbluebutton;
-    use std::env::var;
 
     #[tokio::test]
     #[ignore]
     async fn create_meeting() {
-        let bbb_url = var("BBB_URL").expect("BBB_URL is not set");
-        let bbb_secret = var("BBB_SECRET").expect("BBB_SECRET is not set");
-        let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+        let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
-        let meeting_id = "1".to_string();
+        let meeting_id = "meeting-1".to_string();
         let attendee_pw = "attendeep".to_string();
         let moderator_pw = "modp".to_string();
         let voice_bridge = "70757".to_string();
@@ -24,7 +30,7 @@ mod test {
         request.set_moderator_pw(Some(moderator_pw.clone()));
         request.set_voice_bridge(Some(voice_bridge.clone()));
         request.set_dial_number(Some(dial_number.clone()));
-        request.set_duration(Some(duration.clone()));
+        request.set_duration(Some(duration));
 
         let response = client
             .create_meeting(&request)
@@ -41,15 +47,16 @@ mod test {
     #[tokio::test]
     #[ignore]
     async fn end_meeting() {
-        let bbb_url = var("BBB_URL").expect("BBB_URL is not set");
-        let bbb_secret = var("BBB_SECRET").expect("BBB_SECRET is not set");
-        let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+        let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
-        let mut req = CreateMeetingRequest::new("2");
+        let mut req = CreateMeetingRequest::new("meeting-2");
         req.set_moderator_pw(Some("modp".to_string()));
-        let _ = client.create_meeting(&req).await;
+        client
+            .create_meeting(&req)
+            .await
+            .expect("Unable to parse CreateMeetingResponse");
 
-        let req = EndMeetingRequest::new("2", "modp");
+        let req = EndMeetingRequest::new("meeting-2", "modp");
 
         let response = client
             .end_meeting(&req)
@@ -62,4 +69,902 @@ mod test {
         );
         assert_eq!(response.message_key(), "sentEndMeetingRequest");
     }
+
+    #[test]
+    #[cfg(feature = "webhook")]
+    fn generate_url_includes_api_path() {
+        let client = Bigbluebutton::new("https://example.com/bigbluebutton/", "secret");
+        let url = client
+            .generate_url(
+                "join",
+                vec![
+                    ("meetingID", "meeting-1"),
+                    ("fullName", "Alice Smith"),
+                    ("password", "ap"),
+                ],
+            )
+            .expect("Unable to generate url");
+        assert_eq!(
+            url,
+            "https://example.com/bigbluebutton/api/join?meetingID=meeting-1&fullName=Alice+Smith&password=ap\
+            &checksum=e5b704134733326984a1170572f8dfe03a4b60a4"
+        );
+    }
+
+    #[test]
+    fn validate_valid_request() {
+        let mut request = CreateMeetingRequest::new("meeting-1");
+        request.set_name(Some("Weekly sync".to_string()));
+        request.set_voice_bridge(Some("70757".to_string()));
+        request.set_logout_url(Some("https://example.com/logout".to_string()));
+        request.set_duration(Some(0));
+        request.set_lock_settings_lock_on_join(Some(false));
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_reports_every_violation() {
+        let mut request = CreateMeetingRequest::new("");
+        request.set_name(Some("a".repeat(257)));
+        request.set_voice_bridge(Some("12a45".to_string()));
+        request.set_is_breakout(Some(true));
+        request.set_logout_url(Some("not a url".to_string()));
+
+        let error = request.validate().expect_err("request should be invalid");
+        assert_eq!(
+            error.violations,
+            vec![
+                Violation::InvalidLength {
+                    field: "meetingID",
+                    min: 2,
+                    max: 256,
+                    actual: 0
+                },
+                Violation::InvalidLength {
+                    field: "name",
+                    min: 2,
+                    max: 256,
+                    actual: 257
+                },
+                Violation::InvalidVoiceBridge("12a45".to_string()),
+                Violation::MissingField {
+                    field: "parentMeetingID",
+                    required_by: "isBreakout"
+                },
+                Violation::MissingField {
+                    field: "sequence",
+                    required_by: "isBreakout"
+                },
+                Violation::InvalidUrl {
+                    field: "logoutURL",
+                    value: "not a url".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_parent_meeting_without_breakout() {
+        let mut request = CreateMeetingRequest::new("child");
+        request.set_parent_meeting_id(Some("parent".to_string()));
+
+        let error = request.validate().expect_err("request should be invalid");
+        assert_eq!(
+            error.violations,
+            vec![Violation::MissingField {
+                field: "isBreakout",
+                required_by: "parentMeetingID"
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_meeting_rejects_invalid_request() {
+        let client = Bigbluebutton::new("http://127.0.0.1:1/bigbluebutton/", "secret");
+        let request = CreateMeetingRequest::new("1");
+
+        let error = client
+            .create_meeting(&request)
+            .await
+            .expect_err("request should be rejected before dispatch");
+        let error = error
+            .downcast_ref::<bigbluebutton::error::ValidationError>()
+            .expect("error should be a ValidationError");
+        assert_eq!(error.violations.len(), 1);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn breakout_rooms() {
+        use bigbluebutton::administration::breakout::{
+            BreakoutParticipant, BreakoutPlan, BreakoutRoom,
+        };
+
+        let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
+
+        let mut request = CreateMeetingRequest::new("breakout-parent");
+        request.set_moderator_pw(Some("modp".to_string()));
+        let _ = client.create_meeting(&request).await;
+
+        let plan = BreakoutPlan::new(
+            "breakout-parent",
+            vec![
+                BreakoutRoom::new("Room 1", vec![BreakoutParticipant::new("Alice")]),
+                BreakoutRoom::new("Room 2", vec![BreakoutParticipant::new("Bob")]),
+            ],
+        );
+        let session = client
+            .create_breakout_rooms(&plan)
+            .await
+            .expect("Unable to create breakout rooms");
+        assert_eq!(session.rooms().len(), 2);
+        assert_eq!(session.rooms()[0].join_urls().len(), 1);
+
+        client
+            .end_breakout_rooms(&session)
+            .await
+            .expect("Unable to end breakout rooms");
+    }
+
+    #[tokio::test]
+    async fn breakout_rooms_are_created_joined_and_ended() {
+        use bigbluebutton::administration::breakout::{
+            BreakoutParticipant, BreakoutPlan, BreakoutRoom,
+        };
+
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetingInfo" => (
+                200,
+                meeting_info_response("class-1", &[]).replace(
+                    "<isBreakout>false</isBreakout>",
+                    "<isBreakout>false</isBreakout>\
+                    <breakoutRooms><breakout>class-1-breakout-1-internal</breakout></breakoutRooms>",
+                ),
+            ),
+            "create" if target.contains("meetingID=class-1-breakout-1&") => {
+                (200, create_response("class-1-breakout-1", "success"))
+            }
+            "create" if target.contains("meetingID=class-1-breakout-2&") => {
+                (200, create_response("class-1-breakout-2", "success"))
+            }
+            "end" => (200, end_response()),
+            _ => (404, String::new()),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut teacher = BreakoutParticipant::new("Teacher");
+        teacher.set_moderator(true);
+        let mut alice = BreakoutParticipant::new("Alice");
+        alice.set_user_id(Some("u1".to_string()));
+        let mut plan = BreakoutPlan::new(
+            "class-1",
+            vec![
+                BreakoutRoom::new("Room 1", vec![teacher, alice]),
+                BreakoutRoom::new("Room 2", vec![BreakoutParticipant::new("Bob")]),
+            ],
+        );
+        plan.set_free_join(Some(true));
+
+        let session = client
+            .create_breakout_rooms(&plan)
+            .await
+            .expect("Unable to create breakout rooms");
+        assert_eq!(session.parent_internal_meeting_id(), "class-1-internal");
+        assert_eq!(session.rooms().len(), 2);
+
+        let requests = server.requests();
+        let creates = requests
+            .iter()
+            .filter(|target| api_name(target) == "create")
+            .collect::<Vec<_>>();
+        assert_eq!(creates.len(), 2);
+        for (sequence, create) in creates.iter().enumerate() {
+            assert!(create.contains("isBreakout=true"), "{}", create);
+            assert!(
+                create.contains("parentMeetingID=class-1-internal"),
+                "{}",
+                create
+            );
+            assert!(
+                create.contains(&format!("sequence={}", sequence + 1)),
+                "{}",
+                create
+            );
+            assert!(create.contains("freeJoin=true"), "{}", create);
+        }
+
+        let room = &session.rooms()[0];
+        assert_eq!(*room.sequence(), 1);
+        assert!(*room.tracked());
+        assert!(!*session.rooms()[1].tracked());
+
+        let join_urls = room
+            .join_urls()
+            .iter()
+            .map(|join_url| join_url.url().as_str())
+            .collect::<Vec<_>>();
+        let api = format!("{}api/join?", server.url());
+        assert!(join_urls[0].starts_with(&format!(
+            "{}fullName=Teacher&meetingID=class-1-breakout-1&password=mp&checksum=",
+            api
+        )));
+        assert!(join_urls[1].starts_with(&format!(
+            "{}fullName=Alice&meetingID=class-1-breakout-1&password=ap&userID=u1&checksum=",
+            api
+        )));
+        assert!(session.rooms()[1].join_urls()[0]
+            .url()
+            .contains("fullName=Bob&meetingID=class-1-breakout-2&password=ap&"));
+
+        client
+            .end_breakout_rooms(&session)
+            .await
+            .expect("Unable to end breakout rooms");
+        let ends = server
+            .requests()
+            .into_iter()
+            .filter(|target| api_name(target) == "end")
+            .collect::<Vec<_>>();
+        assert_eq!(ends.len(), 2);
+        assert!(ends[0].contains("meetingID=class-1-breakout-1&password=mp"));
+        assert!(ends[1].contains("meetingID=class-1-breakout-2&password=mp"));
+    }
+
+    #[tokio::test]
+    async fn breakout_rooms_are_ended_when_a_room_fails() {
+        use bigbluebutton::administration::breakout::{BreakoutPlan, BreakoutRoom};
+
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetingInfo" => (200, meeting_info_response("class-1", &[])),
+            "create" if target.contains("meetingID=class-1-breakout-1&") => {
+                (200, create_response("class-1-breakout-1", "success"))
+            }
+            "create" => (200, failed("maxBreakoutRooms", "Too many breakout rooms")),
+            "end" => (200, end_response()),
+            _ => (404, String::new()),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        // one letter names are fine for rooms, only the generated meeting IDs are validated
+        let plan = BreakoutPlan::new(
+            "class-1",
+            vec![
+                BreakoutRoom::new("A", Vec::new()),
+                BreakoutRoom::new("B", Vec::new()),
+            ],
+        );
+        let error = client
+            .create_breakout_rooms(&plan)
+            .await
+            .expect_err("second room should fail");
+        assert!(
+            error.to_string().contains("Too many breakout rooms"),
+            "{}",
+            error
+        );
+
+        let requests = server.requests();
+        let calls = requests
+            .iter()
+            .map(|target| api_name(target))
+            .collect::<Vec<_>>();
+        assert_eq!(calls, vec!["getMeetingInfo", "create", "create", "end"]);
+        assert!(requests[1].contains("name=A&"), "{}", requests[1]);
+        assert!(requests[3].contains("meetingID=class-1-breakout-1&password=mp"));
+    }
+
+    #[tokio::test]
+    async fn breakout_room_ids_are_validated_before_any_room_is_created() {
+        use bigbluebutton::administration::breakout::{BreakoutPlan, BreakoutRoom};
+
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetingInfo" => (200, meeting_info_response("class-1", &[])),
+            _ => (200, create_response("unexpected", "success")),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let plan = BreakoutPlan::new("c".repeat(250), vec![BreakoutRoom::new("A", Vec::new())]);
+        let error = client
+            .create_breakout_rooms(&plan)
+            .await
+            .expect_err("meeting ID is too long");
+        assert_eq!(
+            error
+                .downcast_ref::<bigbluebutton::error::ValidationError>()
+                .map(|error| error.violations.clone()),
+            Some(vec![Violation::InvalidLength {
+                field: "meetingID",
+                min: 2,
+                max: 256,
+                actual: 261
+            }])
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ensure_meeting_outcomes() {
+        let server = MockServer::start(|target| {
+            let meeting_id = ["new", "running", "changed"]
+                .into_iter()
+                .find(|id| target.contains(&format!("meetingID={}&", id)))
+                .expect("unknown meeting");
+            match (api_name(target), meeting_id) {
+                ("getMeetingInfo", _) => (200, meeting_info_response(meeting_id, &[])),
+                ("create", "new") => (200, create_response("new", "success")),
+                ("create", "changed") => (
+                    200,
+                    failed(
+                        "idNotUnique",
+                        "A meeting already exists with that meeting ID.",
+                    ),
+                ),
+                ("create", _) => (200, create_response(meeting_id, "duplicateWarning")),
+                _ => (404, String::new()),
+            }
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let outcome = client
+            .ensure_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to ensure meeting");
+        assert!(matches!(outcome, EnsureMeetingOutcome::Created(_)));
+
+        let mut request = CreateMeetingRequest::new("running");
+        request.set_name(Some("running".to_string()));
+        request.set_voice_bridge(Some("70066".to_string()));
+        let outcome = client
+            .ensure_meeting(&request)
+            .await
+            .expect("Unable to ensure meeting");
+        match outcome {
+            EnsureMeetingOutcome::AlreadyRunning(response) => {
+                assert_eq!(response.internal_meeting_id(), "running-internal");
+                assert_eq!(response.moderator_pw(), "mp");
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+
+        let mut request = CreateMeetingRequest::new("changed");
+        request.set_moderator_pw(Some("other".to_string()));
+        let outcome = client
+            .ensure_meeting(&request)
+            .await
+            .expect("Unable to ensure meeting");
+        match outcome {
+            EnsureMeetingOutcome::ParameterMismatch(mismatch) => {
+                assert_eq!(mismatch.fields(), &vec!["moderatorPW"]);
+                assert_eq!(mismatch.meeting().internal_meeting_id(), "changed-internal");
+                assert_eq!(
+                    mismatch
+                        .error()
+                        .as_ref()
+                        .map(|error| error.message_key.as_str()),
+                    Some("idNotUnique")
+                );
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+
+        let calls = server
+            .requests()
+            .iter()
+            .map(|target| api_name(target).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            calls,
+            vec![
+                "create",
+                "create",
+                "getMeetingInfo",
+                "create",
+                "getMeetingInfo"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn ensure_meeting_reports_mismatched_name_and_voice_bridge() {
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetingInfo" => (200, meeting_info_response("m1", &[])),
+            _ => (200, create_response("m1", "duplicateWarning")),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut request = CreateMeetingRequest::new("m1");
+        request.set_name(Some("Other name".to_string()));
+        let outcome = client
+            .ensure_meeting(&request)
+            .await
+            .expect("Unable to ensure meeting");
+        match outcome {
+            EnsureMeetingOutcome::ParameterMismatch(mismatch) => {
+                assert_eq!(mismatch.fields(), &vec!["name"]);
+                assert!(mismatch.error().is_none());
+                assert_eq!(mismatch.meeting().meeting_name(), "m1");
+                assert_eq!(
+                    mismatch.to_string(),
+                    "meeting m1 is running with a different name"
+                );
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+
+        let mut request = CreateMeetingRequest::new("m1");
+        request.set_name(Some("m1".to_string()));
+        request.set_voice_bridge(Some("12345".to_string()));
+        let outcome = client
+            .ensure_meeting(&request)
+            .await
+            .expect("Unable to ensure meeting");
+        match outcome {
+            EnsureMeetingOutcome::ParameterMismatch(mismatch) => {
+                assert_eq!(mismatch.fields(), &vec!["voiceBridge"]);
+                assert_eq!(mismatch.meeting().voice_bridge(), "70066");
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+    }
+
+    fn client_for(url: &str, version: ServerVersion, mode: CompatibilityMode) -> Bigbluebutton {
+        Bigbluebutton::builder(url, "secret")
+            .server_version(version)
+            .compatibility(mode)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn unsupported_parameters_are_refused_in_error_mode() {
+        let server = MockServer::start(|_| (200, create_response("meeting", ""))).await;
+        let client = client_for(
+            server.url(),
+            ServerVersion::new(2, 4, 0),
+            CompatibilityMode::Error,
+        );
+        let mut request = CreateMeetingRequest::new("meeting");
+        request.set_meeting_layout(Some("SMART_LAYOUT".to_string()));
+        request.set_disabled_features(Some("chat".to_string()));
+
+        let error = client
+            .create_meeting(&request)
+            .await
+            .expect_err("disabledFeatures needs 2.5");
+        let error = error
+            .downcast_ref::<UnsupportedParameterError>()
+            .expect("Expected UnsupportedParameterError");
+        assert_eq!(
+            error.parameters,
+            vec![("disabledFeatures".to_string(), ServerVersion::new(2, 5, 0))]
+        );
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unsupported_parameters_are_sent_in_warn_mode() {
+        let server = MockServer::start(|_| (200, create_response("meeting", ""))).await;
+        let client = client_for(
+            server.url(),
+            ServerVersion::new(2, 2, 0),
+            CompatibilityMode::Warn,
+        );
+        let mut request = CreateMeetingRequest::new("meeting");
+        request.set_disabled_features(Some("chat".to_string()));
+
+        client
+            .create_meeting(&request)
+            .await
+            .expect("Request should be sent");
+        assert!(server.requests()[0].contains("disabledFeatures=chat"));
+    }
+
+    #[test]
+    fn only_print_mode_writes_warnings_on_stderr() {
+        let mut request = JoinMeetingRequest::new("meeting", "Alice");
+        request.set_role(Some("VIEWER".to_string()));
+        if let Some(mode) = std::env::var_os("BBB_WARN_CHILD") {
+            let mode = match mode.to_str() {
+                Some("print") => CompatibilityMode::Print,
+                _ => CompatibilityMode::Warn,
+            };
+            let client = client_for(
+                "https://example.com/bigbluebutton/",
+                ServerVersion::new(2, 5, 3),
+                mode,
+            );
+            assert!(client.join_url(&request).is_ok());
+            return;
+        }
+
+        // the harness captures stderr, so check it from a child running only this test
+        let stderr = |mode: &str| {
+            let output = std::process::Command::new(std::env::current_exe().unwrap())
+                .args([
+                    "test::only_print_mode_writes_warnings_on_stderr",
+                    "--exact",
+                    "--nocapture",
+                ])
+                .env("BBB_WARN_CHILD", mode)
+                .output()
+                .expect("Unable to run test binary");
+            assert!(output.status.success());
+            String::from_utf8_lossy(&output.stderr).into_owned()
+        };
+        let printed = stderr("print");
+        assert!(
+            printed
+                .contains("warning: join parameters not supported by server version 2.5.3: role"),
+            "{}",
+            printed
+        );
+        let warned = stderr("warn");
+        assert!(!warned.contains("warning:"), "{}", warned);
+    }
+
+    #[test]
+    fn join_url_checks_server_version() {
+        let mut request = JoinMeetingRequest::new("meeting", "Alice");
+        request.set_role(Some("VIEWER".to_string()));
+
+        let old = client_for(
+            "https://example.com/bigbluebutton/",
+            ServerVersion::new(2, 5, 3),
+            CompatibilityMode::Error,
+        );
+        assert!(old.join_url(&request).is_err());
+
+        let current = client_for(
+            "https://example.com/bigbluebutton/",
+            ServerVersion::new(2, 6, 0),
+            CompatibilityMode::Error,
+        );
+        let url = current
+            .join_url(&request)
+            .expect("role is supported on 2.6");
+        assert!(url.contains("role=VIEWER"));
+    }
+
+    #[tokio::test]
+    async fn end_all_meetings_warns_then_ends() {
+        use super::common::{meetings_response, MockAttendee};
+        use bigbluebutton::administration::maintenance::{EndOutcome, MaintenancePlan};
+
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetings" => (
+                200,
+                meetings_response(&[
+                    ("m1", vec![MockAttendee::new("u1", "Alice")]),
+                    ("m2", vec![]),
+                ]),
+            ),
+            "end" if target.contains("meetingID=m2") => (
+                200,
+                failed(
+                    "notFound",
+                    "We could not find a meeting with that meeting ID",
+                ),
+            ),
+            _ => (
+                200,
+                "<response><returncode>SUCCESS</returncode>\
+                    <messageKey>sentEndMeetingRequest</messageKey>\
+                    <message>A request to end the meeting was sent.</message></response>"
+                    .to_string(),
+            ),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut plan = MaintenancePlan::new();
+        plan.set_warning(Some("Restarting soon".to_string()));
+        plan.set_concurrency(1);
+        let report = client
+            .end_all_meetings(&plan)
+            .await
+            .expect("Unable to end meetings");
+
+        assert_eq!(report.meetings().len(), 2);
+        assert!(report.meetings().iter().all(|meeting| *meeting.warned()));
+        assert_eq!(report.meetings()[0].outcome(), &EndOutcome::Ended);
+        assert_eq!(
+            report
+                .failed()
+                .map(|meeting| meeting.meeting_id().as_str())
+                .collect::<Vec<_>>(),
+            vec!["m2"]
+        );
+
+        let requests = server.requests();
+        let apis = requests
+            .iter()
+            .map(|target| api_name(target))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            apis,
+            vec![
+                "getMeetings",
+                "sendChatMessage",
+                "sendChatMessage",
+                "end",
+                "end"
+            ]
+        );
+        assert!(requests[1].contains("meetingID=m1&message=Restarting+soon"));
+        assert!(requests[3].contains("meetingID=m1&password=mp"));
+    }
+
+    #[tokio::test]
+    async fn end_all_meetings_dry_run_and_old_servers() {
+        use super::common::meetings_response;
+        use bigbluebutton::administration::maintenance::{EndOutcome, MaintenancePlan};
+        use std::time::Duration;
+
+        let server = MockServer::start(|_| (200, meetings_response(&[("m1", vec![])]))).await;
+        let client = client_for(
+            server.url(),
+            ServerVersion::new(2, 6, 0),
+            CompatibilityMode::Error,
+        );
+
+        let mut plan = MaintenancePlan::new();
+        plan.set_warning(Some("Restarting soon".to_string()));
+        plan.set_grace_period(Duration::from_secs(3600));
+        plan.set_dry_run(true);
+        let report = client
+            .end_all_meetings(&plan)
+            .await
+            .expect("Unable to list meetings");
+        assert_eq!(report.meetings()[0].outcome(), &EndOutcome::WouldEnd);
+        assert!(!report.meetings()[0].warned());
+        assert_eq!(server.requests().len(), 1);
+
+        plan.set_dry_run(false);
+        plan.set_grace_period(Duration::ZERO);
+        let report = client
+            .end_all_meetings(&plan)
+            .await
+            .expect("Unable to end meetings");
+        assert!(!report.meetings()[0].warned());
+        let requests = server.requests();
+        assert_eq!(api_name(&requests[2]), "end");
+    }
+
+    #[test]
+    fn validate_chat_message_lengths() {
+        use bigbluebutton::administration::SendChatMessageRequest;
+
+        let mut request = SendChatMessageRequest::new("12", "x".repeat(501));
+        request.set_user_name(Some(String::new()));
+        let error = request.validate().expect_err("request should be invalid");
+        assert_eq!(
+            error.violations,
+            vec![
+                Violation::InvalidLength {
+                    field: "message",
+                    min: 1,
+                    max: 500,
+                    actual: 501
+                },
+                Violation::InvalidLength {
+                    field: "userName",
+                    min: 1,
+                    max: 255,
+                    actual: 0
+                },
+            ]
+        );
+        assert!(SendChatMessageRequest::new("12", "x".repeat(500))
+            .validate()
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_chat_message() {
+        use super::common::{chat_message_not_found, chat_message_response};
+        use bigbluebutton::administration::SendChatMessageRequest;
+        use bigbluebutton::error::BBBError;
+
+        let server = MockServer::start(|target| {
+            if target.contains("meetingID=missing") {
+                (200, chat_message_not_found())
+            } else {
+                (200, chat_message_response())
+            }
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut request = SendChatMessageRequest::new("12", "Ending soon!");
+        request.set_user_name(Some("Scheduler".to_string()));
+        let response = client
+            .send_chat_message(&request)
+            .await
+            .expect("Unable to parse SendChatMessageResponse");
+        assert_eq!(response.message_key().as_deref(), Some("chatMessageSent"));
+        assert!(server.requests()[0]
+            .contains("sendChatMessage?meetingID=12&message=Ending+soon%21&userName=Scheduler"));
+
+        let error = client
+            .send_chat_message(&SendChatMessageRequest::new("missing", "Hello"))
+            .await
+            .expect_err("meeting should not exist");
+        assert_eq!(
+            error.downcast_ref::<BBBError>().unwrap().message_key,
+            "notFound"
+        );
+        assert!(server.requests()[1].contains("meetingID=missing&message=Hello&checksum="));
+
+        let error = client
+            .send_chat_message(&SendChatMessageRequest::new("12", ""))
+            .await
+            .expect_err("empty message should be rejected");
+        assert!(error
+            .downcast_ref::<bigbluebutton::error::ValidationError>()
+            .is_some());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn join_enter_and_get_join_url() {
+        use super::common::{
+            enter_failed, enter_response, join_session_response, join_url_response,
+        };
+        use bigbluebutton::administration::{EnterRequest, GetJoinUrlRequest};
+        use bigbluebutton::error::BBBError;
+
+        let server = MockServer::start(|target| match api_name(target) {
+            "join" => (200, join_session_response("token1")),
+            "enter" if target.contains("sessionToken=token1") => (200, enter_response("Alice")),
+            "enter" => (200, enter_failed()),
+            _ => (200, join_url_response("https://example.com/new")),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut request = JoinMeetingRequest::new("m1", "Alice");
+        request.set_redirect(Some(true));
+        let joined = client
+            .join(&request)
+            .await
+            .expect("Unable to parse JoinMeetingResponse");
+        assert_eq!(joined.session_token(), "token1");
+        assert_eq!(joined.guest_status().as_deref(), Some("ALLOW"));
+
+        let session = client
+            .enter(&EnterRequest::new(joined.session_token()))
+            .await
+            .expect("Unable to parse EnterResponse");
+        assert_eq!(session.full_name(), "Alice");
+        assert_eq!(session.extern_meeting_id(), "m1");
+        assert!(!session.is_breakout());
+
+        let error = client
+            .enter(&EnterRequest::new("expired"))
+            .await
+            .expect_err("session should be invalid");
+        let error = error.downcast_ref::<BBBError>().unwrap();
+        assert_eq!(error.message_key, "");
+        assert_eq!(error.message, "Could not find conference m1.");
+
+        let request =
+            GetJoinUrlRequest::new("token1").param("userdata-bbb_auto_join_audio", "false");
+        let response = client
+            .get_join_url(&request)
+            .await
+            .expect("Unable to parse GetJoinUrlResponse");
+        assert_eq!(response.url(), "https://example.com/new");
+
+        let requests = server.requests();
+        assert!(requests[0].contains("redirect=false&checksum="));
+        assert!(requests[1].ends_with("/enter?sessionToken=token1"));
+        assert!(requests[3]
+            .ends_with("/getJoinUrl?sessionToken=token1&userdata-bbb_auto_join_audio=false"));
+    }
+
+    #[test]
+    fn get_join_url_params_are_set_through_accessors() {
+        use bigbluebutton::administration::GetJoinUrlRequest;
+
+        let mut request = GetJoinUrlRequest::new("token1").param("a", "1");
+        request
+            .params_mut()
+            .insert("b".to_string(), "2".to_string());
+        assert_eq!(request.params().len(), 2);
+        request.set_params(Default::default());
+        assert!(request.params().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_join_url_requires_3_0() {
+        use bigbluebutton::administration::GetJoinUrlRequest;
+
+        let client = client_for(
+            "http://127.0.0.1:1/bigbluebutton/",
+            ServerVersion::new(2, 7, 0),
+            CompatibilityMode::Error,
+        );
+        let error = client
+            .get_join_url(&GetJoinUrlRequest::new("token1"))
+            .await
+            .expect_err("getJoinUrl is not supported on 2.7");
+        assert!(error.downcast_ref::<UnsupportedParameterError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn client_settings_override_is_sent_as_json() {
+        use bigbluebutton::administration::settings::ClientSettingsOverride;
+
+        let server = MockServer::start(|_| (200, create_response("settings", "success"))).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut settings = ClientSettingsOverride::new();
+        settings.set_skip_check(Some(true));
+        settings.set_override_locale(Some("de".to_string()));
+        let mut request = CreateMeetingRequest::new("settings");
+        request.set_client_settings_override(Some(settings));
+        client
+            .create_meeting(&request)
+            .await
+            .expect("Unable to parse CreateMeetingResponse");
+
+        let expected = Bigbluebutton::encode_query(vec![(
+            "clientSettingsOverride",
+            r#"{"public":{"app":{"defaultSettings":{"application":{"overrideLocale":"de"}},"skipCheck":true}}}"#,
+        )]);
+        assert!(server.requests()[0].contains(&expected));
+
+        let client = client_for(
+            server.url(),
+            ServerVersion::new(2, 6, 0),
+            CompatibilityMode::Error,
+        );
+        let error = client
+            .create_meeting(&request)
+            .await
+            .expect_err("clientSettingsOverride is not supported on 2.6");
+        assert_eq!(
+            error
+                .downcast_ref::<UnsupportedParameterError>()
+                .unwrap()
+                .parameters,
+            vec![(
+                "clientSettingsOverride".to_string(),
+                ServerVersion::new(2, 7, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn user_data_is_sent_as_userdata_parameters() {
+        use bigbluebutton::administration::settings::UserData;
+
+        let client = Bigbluebutton::new("https://server.com/bigbluebutton/", "secret");
+        let mut request = JoinMeetingRequest::new("12", "Alice");
+        let mut user_data = UserData::new().custom("bbb_hide_nav_bar", "true");
+        user_data.set_bbb_auto_share_webcam(Some(false));
+        user_data.set_bbb_custom_style_url(Some("https://cdn.example.com/bbb.css".to_string()));
+        user_data.set_bbb_override_default_locale(Some("fr".to_string()));
+        request.set_user_data(user_data);
+
+        let url = client.join_url(&request).expect("Unable to build join URL");
+        assert!(url.contains(
+            "meetingID=12\
+            &userdata-bbb_auto_share_webcam=false\
+            &userdata-bbb_custom_style_url=https%3A%2F%2Fcdn.example.com%2Fbbb.css\
+            &userdata-bbb_override_default_locale=fr\
+            &userdata-bbb_hide_nav_bar=true\
+            &checksum="
+        ));
+
+        let url = client
+            .join_url(&JoinMeetingRequest::new("12", "Alice"))
+            .expect("Unable to build join URL");
+        assert!(!url.contains("userdata-"));
+    }
 }
@@ -0,0 +1,340 @@
+#![cfg(feature = "balancer")]
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{
+        api_name, create_response, failed, meeting_info_response, meetings_response,
+        recordings_response, running_response, MockAttendee, MockServer,
+    };
+    use bigbluebutton::administration::CreateMeetingRequest;
+    use bigbluebutton::error::BBBError;
+    use bigbluebutton::monitoring::IsMeetingRunningRequest;
+    use bigbluebutton::recording::{GetRecordingsRequest, PublishRecordingsRequest};
+    use bigbluebutton::Bigbluebutton;
+    use std::process::{Child, Command};
+    use std::time::Duration;
+
+    struct Balancer {
+        child: Child,
+        url: String,
+    }
+
+    impl Drop for Balancer {
+        fn drop(&mut self) {
+            let _ = self.child.kill();
+        }
+    }
+
+    async fn start_balancer(servers: &[(&str, &MockServer)], database: &str) -> Balancer {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("Unable to find free port")
+            .port();
+        let servers = servers
+            .iter()
+            .map(|(id, server)| format!("{},{},secret", id, server.url()))
+            .collect::<Vec<_>>()
+            .join(";");
+        let child = Command::new(env!("CARGO_BIN_EXE_bbb-balancer"))
+            .env("BALANCER_SECRET", "frontend")
+            .env("BALANCER_SERVERS", servers)
+            .env("BALANCER_LISTEN", format!("127.0.0.1:{}", port))
+            .env("BALANCER_DATABASE", database)
+            .spawn()
+            .expect("Unable to start balancer");
+        for _ in 0..100 {
+            if tokio::net::TcpStream::connect(("127.0.0.1", port))
+                .await
+                .is_ok()
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        Balancer {
+            child,
+            url: format!("http://127.0.0.1:{}/bigbluebutton/", port),
+        }
+    }
+
+    /// Backend hosting `participants` users in a meeting called `busy`
+    async fn backend(participants: usize) -> MockServer {
+        MockServer::start(move |target| {
+            let attendees = (0..participants)
+                .map(|_| MockAttendee::new("u", "User"))
+                .collect::<Vec<_>>();
+            match api_name(target) {
+                "getMeetings" => (200, meetings_response(&[("busy", attendees)])),
+                "create" => (200, create_response("new", "")),
+                "getMeetingInfo" if target.contains("meetingID=new") => {
+                    (200, meeting_info_response("new", &[]))
+                }
+                "getMeetingInfo" => (200, failed("notFound", "meeting not found")),
+                "isMeetingRunning" => (200, running_response(true)),
+                _ => (404, String::new()),
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn routes_meeting_to_its_server_and_persists_affinity() {
+        let busy = backend(3).await;
+        let idle = backend(0).await;
+        let database =
+            std::env::temp_dir().join(format!("bbb-balancer-{}.sqlite", std::process::id()));
+        let database = database.to_str().expect("Invalid temp path").to_string();
+        let _ = std::fs::remove_file(&database);
+
+        let balancer = start_balancer(&[("busy", &busy), ("idle", &idle)], &database).await;
+        let client = Bigbluebutton::new(&balancer.url, "frontend");
+        client
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert!(idle.requests().iter().any(|r| api_name(r) == "create"));
+        assert!(!busy.requests().iter().any(|r| api_name(r) == "create"));
+        drop(balancer);
+
+        // A restarted balancer routes the meeting from the persisted affinity
+        let balancer = start_balancer(&[("busy", &busy), ("idle", &idle)], &database).await;
+        let client = Bigbluebutton::new(&balancer.url, "frontend");
+        let response = client
+            .is_meeting_running(&IsMeetingRunningRequest::new("new"))
+            .await
+            .expect("Unable to check meeting");
+        assert!(response.running);
+        assert!(idle
+            .requests()
+            .iter()
+            .any(|r| api_name(r) == "isMeetingRunning"));
+        assert!(!busy
+            .requests()
+            .iter()
+            .any(|r| api_name(r) == "isMeetingRunning"));
+        let _ = std::fs::remove_file(&database);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_checksum() {
+        let server = backend(0).await;
+        let database = std::env::temp_dir().join(format!(
+            "bbb-balancer-checksum-{}.sqlite",
+            std::process::id()
+        ));
+        let database = database.to_str().expect("Invalid temp path").to_string();
+        let balancer = start_balancer(&[("only", &server)], &database).await;
+
+        let client = Bigbluebutton::new(&balancer.url, "wrong");
+        let error = client
+            .is_meeting_running(&IsMeetingRunningRequest::new("new"))
+            .await
+            .expect_err("Checksum should be rejected");
+        assert_eq!(
+            error
+                .downcast_ref::<BBBError>()
+                .map(|error| error.message_key.as_str()),
+            Some("checksumError")
+        );
+        assert!(server.requests().is_empty());
+        let _ = std::fs::remove_file(&database);
+    }
+
+    /// Value of a query parameter of a request target
+    fn query_param(target: &str, key: &str) -> Option<String> {
+        let query = target.split_once('?')?.1;
+        url::form_urlencoded::parse(query.as_bytes())
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// Backend holding `(record_id, meeting_id)` recordings, failing publish when `fail_publish`
+    async fn recordings_backend(
+        recordings: &'static [(&'static str, &'static str)],
+        fail_publish: bool,
+    ) -> MockServer {
+        MockServer::start(move |target| match api_name(target) {
+            "getRecordings" => {
+                let record_ids = query_param(target, "recordID");
+                let meeting_ids = query_param(target, "meetingID");
+                let listed = |ids: &Option<String>, id: &str| match ids {
+                    Some(ids) => ids.split(',').any(|listed| listed == id),
+                    None => true,
+                };
+                let matching = recordings
+                    .iter()
+                    .filter(|(record_id, meeting_id)| {
+                        listed(&record_ids, record_id) && listed(&meeting_ids, meeting_id)
+                    })
+                    .map(|(record_id, meeting_id)| (*record_id, *meeting_id, true))
+                    .collect::<Vec<_>>();
+                (200, recordings_response(&matching))
+            }
+            "publishRecordings" if fail_publish => {
+                (200, failed("publishFailed", "Unable to publish"))
+            }
+            "publishRecordings" => (
+                200,
+                "<response><returncode>SUCCESS</returncode><published>true</published></response>"
+                    .to_string(),
+            ),
+            _ => (404, String::new()),
+        })
+        .await
+    }
+
+    fn database(name: &str) -> String {
+        let database = std::env::temp_dir().join(format!(
+            "bbb-balancer-{}-{}.sqlite",
+            name,
+            std::process::id()
+        ));
+        let database = database.to_str().expect("Invalid temp path").to_string();
+        let _ = std::fs::remove_file(&database);
+        database
+    }
+
+    #[tokio::test]
+    async fn get_recordings_filters_reach_every_backend() {
+        let first = recordings_backend(&[("r1", "m1"), ("r2", "other")], false).await;
+        let second = recordings_backend(&[("r3", "tenant-b")], false).await;
+        let database = database("recordings");
+        let balancer = start_balancer(&[("first", &first), ("second", &second)], &database).await;
+        let client = Bigbluebutton::new(&balancer.url, "frontend");
+
+        let mut request = GetRecordingsRequest::new();
+        request.meeting_id = Some("m1".to_string());
+        let response = client
+            .get_recordings(&request)
+            .await
+            .expect("Unable to parse GetRecordingsResponse");
+        let record_ids = response
+            .recordings()
+            .iter()
+            .map(|recording| recording.record_id().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(record_ids, ["r1"]);
+        for backend in [&first, &second] {
+            let requests = backend.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(
+                query_param(&requests[0], "meetingID").as_deref(),
+                Some("m1")
+            );
+        }
+        let _ = std::fs::remove_file(&database);
+    }
+
+    #[tokio::test]
+    async fn recording_calls_go_to_the_owning_backend() {
+        let first = recordings_backend(&[("r1", "m1")], false).await;
+        let second = recordings_backend(&[("r2", "m2")], false).await;
+        let third = recordings_backend(&[("r3", "m3")], false).await;
+        let database = database("publish");
+        let balancer = start_balancer(
+            &[("first", &first), ("second", &second), ("third", &third)],
+            &database,
+        )
+        .await;
+        let client = Bigbluebutton::new(&balancer.url, "frontend");
+
+        client
+            .publish_recordings(&PublishRecordingsRequest::new("r1,r2", false))
+            .await
+            .expect("Unable to publish recordings");
+        let published = |backend: &MockServer| {
+            backend
+                .requests()
+                .into_iter()
+                .filter(|target| api_name(target) == "publishRecordings")
+                .map(|target| query_param(&target, "recordID").unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(published(&first), ["r1"]);
+        assert_eq!(published(&second), ["r2"]);
+        assert!(published(&third).is_empty());
+
+        let error = client
+            .publish_recordings(&PublishRecordingsRequest::new("unknown", true))
+            .await
+            .expect_err("unknown recordings should not be found");
+        assert_eq!(
+            error
+                .downcast_ref::<BBBError>()
+                .map(|error| error.message_key.as_str()),
+            Some("notFound")
+        );
+        assert_eq!(published(&first).len(), 1);
+        let _ = std::fs::remove_file(&database);
+    }
+
+    #[tokio::test]
+    async fn recording_call_failures_are_not_hidden() {
+        let healthy = recordings_backend(&[("r1", "m1")], false).await;
+        let failing = recordings_backend(&[("r2", "m2")], true).await;
+        let database = database("failures");
+        let balancer =
+            start_balancer(&[("healthy", &healthy), ("failing", &failing)], &database).await;
+        let client = Bigbluebutton::new(&balancer.url, "frontend");
+
+        let error = client
+            .publish_recordings(&PublishRecordingsRequest::new("r2", true))
+            .await
+            .expect_err("failure of the owning backend should be returned");
+        assert_eq!(
+            error
+                .downcast_ref::<BBBError>()
+                .map(|error| error.message_key.as_str()),
+            Some("publishFailed")
+        );
+        assert!(healthy
+            .requests()
+            .iter()
+            .all(|target| api_name(target) == "getRecordings"));
+        let _ = std::fs::remove_file(&database);
+    }
+
+    #[tokio::test]
+    async fn create_forwards_the_post_body() {
+        let server = backend(0).await;
+        let database = database("create-body");
+        let balancer = start_balancer(&[("only", &server)], &database).await;
+
+        let presentation = "<modules><module name=\"presentation\">\
+            <document url=\"https://example.com/slides.pdf\" filename=\"slides.pdf\"/>\
+            </module></modules>";
+        let url = Bigbluebutton::new(&balancer.url, "frontend")
+            .generate_url("create", vec![("meetingID", "new")])
+            .expect("Unable to generate url");
+        let response = reqwest::Client::new()
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "application/xml")
+            .body(presentation)
+            .send()
+            .await
+            .expect("Unable to reach balancer")
+            .text()
+            .await
+            .expect("Unable to read response");
+        assert!(
+            response.contains("<returncode>SUCCESS</returncode>"),
+            "{}",
+            response
+        );
+
+        let creates = server
+            .requests()
+            .into_iter()
+            .zip(server.bodies())
+            .filter(|(target, _)| api_name(target) == "create")
+            .collect::<Vec<_>>();
+        assert_eq!(creates.len(), 1);
+        assert_eq!(
+            query_param(&creates[0].0, "meetingID").as_deref(),
+            Some("new")
+        );
+        assert_eq!(creates[0].1, presentation);
+        let _ = std::fs::remove_file(&database);
+    }
+}
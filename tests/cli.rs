@@ -0,0 +1,153 @@
+#![cfg(feature = "cli")]
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{
+        api_name, meetings_response, recordings_response, MockAttendee, MockServer,
+    };
+    use std::process::{Command, Output};
+
+    async fn bbb(url: &str, args: &[&str]) -> Output {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bbb"));
+        command
+            .args(args)
+            .env_remove("BBB_CONFIG")
+            .env("BBB_URL", url)
+            .env("BBB_SECRET", "secret");
+        tokio::task::spawn_blocking(move || command.output().expect("Unable to run bbb"))
+            .await
+            .unwrap()
+    }
+
+    async fn backend() -> MockServer {
+        MockServer::start(|target| match api_name(target) {
+            "getMeetings" => (
+                200,
+                meetings_response(&[
+                    ("m1", vec![MockAttendee::new("u1", "Alice")]),
+                    ("m2", vec![]),
+                ]),
+            ),
+            "getRecordings" => (200, recordings_response(&[("r1", "m1", true)])),
+            _ => (
+                200,
+                "<response><returncode>SUCCESS</returncode><published>false</published></response>"
+                    .to_string(),
+            ),
+        })
+        .await
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lists_meetings_as_table() {
+        let server = backend().await;
+
+        let output = bbb(server.url(), &["meetings", "list"]).await;
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines = stdout.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("meeting_id"));
+        assert!(lines[1].starts_with("m1 "));
+        assert!(lines[2].starts_with("m2 "));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn renders_json_and_csv() {
+        let server = backend().await;
+
+        let output = bbb(server.url(), &["recordings", "list", "--output", "json"]).await;
+        assert!(output.status.success());
+        let json = serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap();
+        assert_eq!(json[0]["record_id"], "r1");
+        assert_eq!(json[0]["published"], "true");
+        assert_eq!(json[0]["playback_url"], "https://example.com/playback/r1");
+
+        let output = bbb(
+            server.url(),
+            &["recordings", "unpublish", "r1", "-o", "csv"],
+        )
+        .await;
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "record_id,published\nr1,false\n"
+        );
+        assert!(server
+            .requests()
+            .last()
+            .unwrap()
+            .contains("recordID=r1&publish=false"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn end_all_dry_run_only_lists() {
+        let server = backend().await;
+
+        let output = bbb(
+            server.url(),
+            &[
+                "meetings",
+                "end-all",
+                "--dry-run",
+                "--warning",
+                "Bye",
+                "-o",
+                "csv",
+            ],
+        )
+        .await;
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "meeting_id,name,participants,warned,outcome\nm1,m1,1,false,would end\nm2,m2,0,false,would end\n"
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn join_url_is_signed_locally() {
+        let server = backend().await;
+
+        let output = bbb(
+            server.url(),
+            &["join-url", "m1", "Alice", "--role", "VIEWER"],
+        )
+        .await;
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("/api/join?fullName=Alice&meetingID=m1&role=VIEWER&checksum="));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_errors_on_stderr() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                super::common::failed(
+                    "checksumError",
+                    "You did not pass the checksum security check",
+                ),
+            )
+        })
+        .await;
+
+        let output = bbb(server.url(), &["meetings", "list"]).await;
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("You did not pass the checksum security check"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn secret_is_not_accepted_on_the_command_line() {
+        let server = backend().await;
+
+        let output = bbb(server.url(), &["meetings", "list", "--secret", "secret"]).await;
+        assert!(!output.status.success());
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,394 @@
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{
+        api_name, create_response, failed, meeting_info_response, meetings_response, MockServer,
+    };
+    use bigbluebutton::administration::CreateMeetingRequest;
+    use bigbluebutton::error::{BBBError, BaseUrlError, HttpError, ProbeError};
+    use bigbluebutton::limit::{Limits, RateLimit};
+    use bigbluebutton::monitoring::{GetMeetingInfoRequest, GetMeetingsRequest};
+    use bigbluebutton::retry::RetryPolicy;
+    use bigbluebutton::version::ServerVersion;
+    use bigbluebutton::{Bigbluebutton, SecretMatch};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Fails the first `failures` requests with a 502, then delegates to `body`
+    async fn flaky_server(failures: usize, body: fn(&str) -> String) -> MockServer {
+        let count = Arc::new(AtomicUsize::new(0));
+        MockServer::start(move |target| {
+            if count.fetch_add(1, Ordering::SeqCst) < failures {
+                (502, "<html>Bad Gateway</html>".to_string())
+            } else {
+                (200, body(target))
+            }
+        })
+        .await
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(3)
+            .initial_backoff(Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[tokio::test]
+    async fn read_only_calls_are_retried() {
+        let server = flaky_server(2, |_| meetings_response(&[])).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .retry_policy(fast_policy())
+            .build();
+
+        let response = client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect("Request should succeed after retries");
+        assert!(response.meetings().is_empty());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_max_attempts() {
+        let server = flaky_server(5, |_| meetings_response(&[])).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .retry_policy(fast_policy())
+            .build();
+
+        let error = client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect_err("Request should fail");
+        assert_eq!(
+            error.downcast_ref::<HttpError>(),
+            Some(&HttpError { status: 502 })
+        );
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn mutating_calls_are_not_retried_by_default() {
+        let server = flaky_server(1, |_| create_response("meeting", "")).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .retry_policy(fast_policy())
+            .build();
+
+        client
+            .create_meeting(&CreateMeetingRequest::new("meeting"))
+            .await
+            .expect_err("Create should not be retried");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn mutating_calls_are_retried_when_opted_in() {
+        let server = flaky_server(1, |_| create_response("meeting", "")).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .retry_policy(fast_policy().retry_mutating("create"))
+            .build();
+
+        client
+            .create_meeting(&CreateMeetingRequest::new("meeting"))
+            .await
+            .expect("Create should be retried");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|target| api_name(target) == "create"));
+    }
+
+    #[tokio::test]
+    async fn client_without_policy_does_not_retry() {
+        let server = flaky_server(1, |_| meetings_response(&[])).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect_err("Request should fail");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_spaces_requests() {
+        let server = MockServer::start(|_| (200, meetings_response(&[]))).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .rate_limit(RateLimit::new(2, Duration::from_secs(1)))
+            .build();
+
+        let started = tokio::time::Instant::now();
+        for _ in 0..6 {
+            client
+                .get_meetings(&GetMeetingsRequest::new())
+                .await
+                .expect("Unable to parse GetMeetingsResponse");
+        }
+        // 2 requests use the initial burst, the 4 remaining wait 500ms each
+        assert!(started.elapsed() >= Duration::from_secs(2));
+        assert_eq!(server.requests().len(), 6);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn max_in_flight_caps_concurrent_requests() {
+        let server = MockServer::start_with_delay(Duration::from_millis(100), |_| {
+            (200, meeting_info_response("1", &[]))
+        })
+        .await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .max_in_flight(2)
+            .build();
+
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("1".to_string());
+        let calls = (0..6).map(|_| client.get_meeting_info(&request));
+        for response in futures::future::join_all(calls).await {
+            response.expect("Unable to parse GetMeetingInfoResponse");
+        }
+        assert_eq!(server.requests().len(), 6);
+        assert_eq!(server.peak_in_flight(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn endpoint_limits_override_client_limits() {
+        let server = MockServer::start(|_| (200, meetings_response(&[]))).await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .rate_limit(RateLimit::new(1, Duration::from_secs(10)))
+            .endpoint_limits(
+                "getMeetings",
+                Limits::new().rate_limit(RateLimit::new(10, Duration::from_secs(1))),
+            )
+            .build();
+
+        let started = tokio::time::Instant::now();
+        for _ in 0..5 {
+            client
+                .get_meetings(&GetMeetingsRequest::new())
+                .await
+                .expect("Unable to parse GetMeetingsResponse");
+        }
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn debug_output_hides_secret() {
+        let client = Bigbluebutton::new("https://example.com/bigbluebutton/", "very-secret-salt");
+        let output = format!("{:?}", client);
+        assert!(output.contains("https://example.com/bigbluebutton/api/"));
+        assert!(!output.contains("very-secret-salt"));
+
+        let builder =
+            Bigbluebutton::builder("https://example.com/bigbluebutton/", "very-secret-salt");
+        assert!(!format!("{:?}", builder).contains("very-secret-salt"));
+    }
+
+    #[tokio::test]
+    async fn connection_errors_hide_passwords_and_checksum() {
+        let client = Bigbluebutton::new("http://127.0.0.1:1/bigbluebutton/", "secret");
+        let mut request = CreateMeetingRequest::new("meeting");
+        request.set_moderator_pw(Some("moderator-password".to_string()));
+        request.set_attendee_pw(Some("attendee-password".to_string()));
+
+        let error = client
+            .create_meeting(&request)
+            .await
+            .expect_err("Connection should fail");
+        let output = format!("{:?} {:#}", error, error);
+        assert!(output.contains("moderatorPW=***"));
+        assert!(!output.contains("moderator-password"));
+        assert!(!output.contains("attendee-password"));
+        assert!(output.contains("checksum=***"));
+    }
+
+    fn version_response(bbb_version: &str) -> String {
+        format!(
+            "<response><returncode>SUCCESS</returncode><version>2.0</version>\
+            <apiVersion>2.0</apiVersion><bbbVersion>{}</bbbVersion></response>",
+            bbb_version
+        )
+    }
+
+    #[tokio::test]
+    async fn probe_reports_version_and_capabilities() {
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetings" => (200, meetings_response(&[])),
+            _ => (200, version_response("2.6.10")),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let info = client.probe().await.expect("Probe should succeed");
+        assert_eq!(info.version, "2.0");
+        assert_eq!(info.api_version.as_deref(), Some("2.0"));
+        assert_eq!(info.server_version, Some(ServerVersion::new(2, 6, 10)));
+        assert!(info.capabilities.disabled_features);
+        assert!(!info.capabilities.send_chat_message);
+        assert_eq!(server.requests()[0], "/bigbluebutton/api");
+    }
+
+    #[tokio::test]
+    async fn probe_reports_rejected_checksum() {
+        let server = MockServer::start(|target| match api_name(target) {
+            "getMeetings" => (200, failed("checksumError", "Checksums do not match")),
+            _ => (200, version_response("3.0.0")),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "wrong");
+
+        let error = client.probe().await.expect_err("Probe should fail");
+        assert!(matches!(
+            error.downcast_ref::<ProbeError>(),
+            Some(ProbeError::ChecksumRejected { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn probe_reports_an_outdated_primary_secret() {
+        let verifier = Bigbluebutton::new("http://localhost/bigbluebutton/", "new");
+        let server = MockServer::start(move |target| {
+            let query = target.split_once('?').map_or("", |(_, query)| query);
+            match api_name(target) {
+                "getMeetings" if verifier.verify_checksum("getMeetings", query) => {
+                    (200, meetings_response(&[]))
+                }
+                "getMeetings" => (200, failed("checksumError", "Checksums do not match")),
+                _ => (200, version_response("3.0.0")),
+            }
+        })
+        .await;
+
+        let client = Bigbluebutton::builder(server.url(), "old")
+            .fallback_secret("older")
+            .fallback_secret("new")
+            .build();
+        let info = client.probe().await.expect("Probe should succeed");
+        assert_eq!(info.secret, SecretMatch::Fallback(1));
+
+        let client = Bigbluebutton::builder(server.url(), "new")
+            .fallback_secret("old")
+            .build();
+        let info = client.probe().await.expect("Probe should succeed");
+        assert_eq!(info.secret, SecretMatch::Primary);
+        let checks = server
+            .requests()
+            .into_iter()
+            .filter(|target| api_name(target) == "getMeetings")
+            .count();
+        assert_eq!(checks, 4);
+    }
+
+    #[test]
+    fn base_url_shapes_resolve_to_api_root() {
+        for url in [
+            "https://example.com/bigbluebutton/",
+            "https://example.com/bigbluebutton",
+            "https://example.com/bigbluebutton/api",
+            "https://example.com/bigbluebutton/api/",
+            " https://example.com/bigbluebutton// ",
+        ] {
+            let client = Bigbluebutton::try_new(url, "secret").expect("Valid url");
+            let generated = client
+                .generate_url("join", vec![("meetingID", "1")])
+                .expect("Unable to generate url");
+            assert!(
+                generated.starts_with("https://example.com/bigbluebutton/api/join?meetingID=1"),
+                "{} generated {}",
+                url,
+                generated
+            );
+        }
+
+        let client = Bigbluebutton::try_new("http://10.0.0.1:8080", "secret").expect("Valid url");
+        let generated = client
+            .generate_url("join", vec![("meetingID", "1")])
+            .expect("Unable to generate url");
+        assert!(generated.starts_with("http://10.0.0.1:8080/api/join?"));
+    }
+
+    #[test]
+    fn invalid_base_urls_are_rejected() {
+        assert!(matches!(
+            Bigbluebutton::try_new("ftp://example.com/bigbluebutton/", "secret"),
+            Err(BaseUrlError::UnsupportedScheme(scheme)) if scheme == "ftp"
+        ));
+        assert!(matches!(
+            Bigbluebutton::try_new("example.com/bigbluebutton/", "secret"),
+            Err(BaseUrlError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn new_keeps_unparsable_urls() {
+        let client = Bigbluebutton::new("not a url/", "secret");
+        assert!(format!("{:?}", client).contains("not a url/api/"));
+    }
+
+    /// Server only accepting calls signed with `secret`
+    async fn server_accepting(secret: &'static str) -> MockServer {
+        let verifier = Bigbluebutton::new("http://localhost/bigbluebutton/", secret);
+        MockServer::start(move |target| {
+            let query = target.split_once('?').map_or("", |(_, query)| query);
+            if verifier.verify_checksum(api_name(target), query) {
+                (200, meetings_response(&[]))
+            } else {
+                (200, failed("checksumError", "Checksums do not match"))
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn checksum_errors_are_retried_with_fallback_secret() {
+        let server = server_accepting("new").await;
+        let client = Bigbluebutton::builder(server.url(), "old")
+            .fallback_secret("new")
+            .build();
+
+        client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect("Fallback secret should be accepted");
+        assert_eq!(server.requests().len(), 2);
+
+        let client = Bigbluebutton::new(server.url(), "old");
+        let error = client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect_err("Primary secret should be rejected");
+        assert_eq!(
+            error
+                .downcast_ref::<BBBError>()
+                .map(|error| error.message_key.as_str()),
+            Some("checksumError")
+        );
+    }
+
+    #[test]
+    fn verification_reports_matching_secret() {
+        let client = Bigbluebutton::builder("https://example.com/bigbluebutton/", "new")
+            .fallback_secret("old")
+            .fallback_secret("older")
+            .build();
+        let signed_by = |secret| {
+            let url = Bigbluebutton::new("https://example.com/bigbluebutton/", secret)
+                .generate_url("end", vec![("meetingID", "1")])
+                .expect("Unable to generate url");
+            url.split_once('?')
+                .expect("url without query")
+                .1
+                .to_string()
+        };
+
+        assert_eq!(
+            client.matching_secret("end", &signed_by("new")),
+            Some(SecretMatch::Primary)
+        );
+        assert_eq!(
+            client.matching_secret("end", &signed_by("older")),
+            Some(SecretMatch::Fallback(1))
+        );
+        assert_eq!(client.matching_secret("end", &signed_by("other")), None);
+        assert!(!format!("{:?}", client).contains("older"));
+    }
+}
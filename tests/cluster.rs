@@ -0,0 +1,306 @@
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{
+        api_name, create_response, failed, meeting_info_response, meetings_response, MockAttendee,
+        MockServer,
+    };
+    use bigbluebutton::administration::{CreateMeetingRequest, EndMeetingRequest};
+    use bigbluebutton::cluster::{BigbluebuttonCluster, ServerHealth};
+    use bigbluebutton::error::ClusterError;
+    use bigbluebutton::monitoring::GetMeetingInfoRequest;
+    use bigbluebutton::Bigbluebutton;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Mock server hosting `participants` users in a single meeting called `busy`
+    async fn server_with_load(participants: usize) -> MockServer {
+        MockServer::start(move |target| {
+            let attendees = (0..participants)
+                .map(|_| MockAttendee::new("u", "User"))
+                .collect::<Vec<_>>();
+            match api_name(target) {
+                "getMeetings" => (200, meetings_response(&[("busy", attendees)])),
+                "create" => (200, create_response("new", "")),
+                "getMeetingInfo" if target.contains("meetingID=new") => {
+                    (200, meeting_info_response("new", &[]))
+                }
+                "getMeetingInfo" => (200, failed("notFound", "meeting not found")),
+                "end" => (
+                    200,
+                    "<response><returncode>SUCCESS</returncode><messageKey>sentEndMeetingRequest</messageKey><message>ok</message></response>".to_string(),
+                ),
+                _ => (404, String::new()),
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn create_meeting_uses_least_loaded_server() {
+        let busy = server_with_load(5).await;
+        let idle = server_with_load(1).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("busy", Bigbluebutton::new(busy.url(), "secret"), 1)
+            .add_server("idle", Bigbluebutton::new(idle.url(), "secret"), 1);
+
+        let (server_id, _) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "idle");
+        assert_eq!(cluster.server_for("new"), Some("idle".to_string()));
+
+        let join_url = cluster
+            .join_url("new", vec![("fullName", "Alice"), ("password", "ap")])
+            .expect("Unable to generate join url");
+        assert!(join_url.starts_with(idle.url()));
+        assert!(join_url.contains("meetingID=new"));
+
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("new".to_string());
+        cluster
+            .get_meeting_info(&request)
+            .await
+            .expect("Unable to parse GetMeetingInfoResponse");
+        cluster
+            .end_meeting(&EndMeetingRequest::new("new", "mp"))
+            .await
+            .expect("Unable to parse EndMeetingResponse");
+        assert_eq!(cluster.server_for("new"), None);
+        assert!(busy
+            .requests()
+            .iter()
+            .all(|target| api_name(target) == "getMeetings"));
+    }
+
+    #[tokio::test]
+    async fn weights_scale_server_load() {
+        let large = server_with_load(4).await;
+        let small = server_with_load(3).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("large", Bigbluebutton::new(large.url(), "secret"), 4)
+            .add_server("small", Bigbluebutton::new(small.url(), "secret"), 1);
+
+        let (server_id, _) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "large");
+    }
+
+    #[tokio::test]
+    async fn unhealthy_servers_are_skipped() {
+        let healthy = server_with_load(10).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server(
+                "down",
+                Bigbluebutton::new("http://127.0.0.1:1/bigbluebutton/", "secret"),
+                1,
+            )
+            .add_server("up", Bigbluebutton::new(healthy.url(), "secret"), 1);
+
+        let (server_id, _) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "up");
+        assert!(matches!(
+            cluster.health("down"),
+            Some(ServerHealth::Unhealthy { .. })
+        ));
+        assert_eq!(cluster.health("up"), Some(ServerHealth::Healthy));
+    }
+
+    #[tokio::test]
+    async fn unknown_meetings_are_located() {
+        let first = server_with_load(0).await;
+        let second = MockServer::start(|target| match api_name(target) {
+            "getMeetingInfo" => (200, meeting_info_response("elsewhere", &[])),
+            _ => (404, String::new()),
+        })
+        .await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("first", Bigbluebutton::new(first.url(), "secret"), 1)
+            .add_server("second", Bigbluebutton::new(second.url(), "secret"), 1);
+
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("elsewhere".to_string());
+        cluster
+            .get_meeting_info(&request)
+            .await
+            .expect("Meeting should be found on the second server");
+        assert_eq!(cluster.server_for("elsewhere"), Some("second".to_string()));
+        assert_eq!(cluster.health("first"), Some(ServerHealth::Healthy));
+    }
+
+    #[tokio::test]
+    async fn meetings_are_not_placed_again_while_owner_is_down() {
+        let idle = server_with_load(0).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server(
+                "owner",
+                Bigbluebutton::new("http://127.0.0.1:1/bigbluebutton/", "secret"),
+                1,
+            )
+            .add_server("idle", Bigbluebutton::new(idle.url(), "secret"), 1);
+        cluster.set_affinity("new", "owner");
+
+        // first attempt reaches the owner, the second one finds it in cooldown
+        for _ in 0..2 {
+            let error = cluster
+                .create_meeting(&CreateMeetingRequest::new("new"))
+                .await
+                .expect_err("meeting may still run on its owner");
+            assert_eq!(
+                error.downcast_ref::<ClusterError>(),
+                Some(&ClusterError::OwnerUnavailable {
+                    meeting_id: "new".to_string(),
+                    server_id: "owner".to_string(),
+                })
+            );
+        }
+        assert_eq!(cluster.server_for("new"), Some("owner".to_string()));
+        assert!(idle.requests().is_empty());
+    }
+
+    /// Mock owner of meeting `new` with one participant, answering getMeetingInfo with `info`
+    async fn owner(info: &'static str) -> MockServer {
+        MockServer::start(move |target| match api_name(target) {
+            "getMeetings" => (
+                200,
+                meetings_response(&[("new", vec![MockAttendee::new("u", "User")])]),
+            ),
+            "getMeetingInfo" => match info {
+                "running" => (200, meeting_info_response("new", &[])),
+                "not joined" => (
+                    200,
+                    meeting_info_response("new", &[])
+                        .replace("<running>true</running>", "<running>false</running>")
+                        .replace(
+                            "<hasUserJoined>true</hasUserJoined>",
+                            "<hasUserJoined>false</hasUserJoined>",
+                        ),
+                ),
+                _ => (
+                    200,
+                    failed(
+                        "notFound",
+                        "We could not find a meeting with that meeting ID",
+                    ),
+                ),
+            },
+            "create" => (200, create_response("new", "duplicateWarning")),
+            _ => (404, String::new()),
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn meetings_stay_on_owner_until_it_no_longer_knows_them() {
+        let running = owner("running").await;
+        let ended = owner("ended").await;
+        let idle = server_with_load(0).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("running", Bigbluebutton::new(running.url(), "secret"), 1)
+            .add_server("ended", Bigbluebutton::new(ended.url(), "secret"), 1)
+            .add_server("idle", Bigbluebutton::new(idle.url(), "secret"), 1);
+
+        cluster.set_affinity("new", "running");
+        let (server_id, _) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "running");
+        assert!(running.requests()[0].contains("getMeetingInfo?meetingID=new"));
+
+        cluster.set_affinity("new", "ended");
+        let (server_id, _) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "idle");
+        assert_eq!(cluster.server_for("new"), Some("idle".to_string()));
+        assert!(ended
+            .requests()
+            .iter()
+            .all(|target| api_name(target) != "create"));
+    }
+
+    #[tokio::test]
+    async fn meetings_nobody_joined_yet_stay_on_owner() {
+        let created = owner("not joined").await;
+        let idle = server_with_load(0).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("created", Bigbluebutton::new(created.url(), "secret"), 1)
+            .add_server("idle", Bigbluebutton::new(idle.url(), "secret"), 1);
+
+        cluster.set_affinity("new", "created");
+        let (server_id, response) = cluster
+            .create_meeting(&CreateMeetingRequest::new("new"))
+            .await
+            .expect("Unable to create meeting");
+        assert_eq!(server_id, "created");
+        assert_eq!(response.message_key(), "duplicateWarning");
+        assert_eq!(cluster.server_for("new"), Some("created".to_string()));
+        assert!(idle.requests().is_empty());
+    }
+
+    /// Mock server reporting `participants` on the first getMeetings call and a hundred more on
+    /// later ones, counting the creates it receives
+    async fn server_filling_up(participants: usize) -> (MockServer, Arc<AtomicUsize>) {
+        let polls = AtomicUsize::new(0);
+        let creates = Arc::new(AtomicUsize::new(0));
+        let counter = creates.clone();
+        let server = MockServer::start(move |target| {
+            let created = counter.load(Ordering::SeqCst);
+            match api_name(target) {
+                "getMeetings" => {
+                    let extra = if polls.fetch_add(1, Ordering::SeqCst) == 0 {
+                        0
+                    } else {
+                        100
+                    };
+                    let attendees = (0..participants + extra)
+                        .map(|_| MockAttendee::new("u", "User"))
+                        .collect::<Vec<_>>();
+                    (200, meetings_response(&[("busy", attendees)]))
+                }
+                "getMeetingInfo" if created > 0 => (200, meeting_info_response("new", &[])),
+                "getMeetingInfo" => (200, failed("notFound", "meeting not found")),
+                "create" => {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let key = if created > 0 { "duplicateWarning" } else { "" };
+                    (200, create_response("new", key))
+                }
+                _ => (404, String::new()),
+            }
+        })
+        .await;
+        (server, creates)
+    }
+
+    #[tokio::test]
+    async fn concurrent_creates_of_a_new_meeting_use_one_server() {
+        let (first, first_creates) = server_filling_up(0).await;
+        let second = server_with_load(5).await;
+        let cluster = BigbluebuttonCluster::new()
+            .add_server("first", Bigbluebutton::new(first.url(), "secret"), 1)
+            .add_server("second", Bigbluebutton::new(second.url(), "secret"), 1);
+
+        let request = CreateMeetingRequest::new("new");
+        let results =
+            futures::future::join_all((0..5).map(|_| cluster.create_meeting(&request))).await;
+        for result in results {
+            let (server_id, _) = result.expect("Unable to create meeting");
+            assert_eq!(server_id, "first");
+        }
+        assert_eq!(first_creates.load(Ordering::SeqCst), 5);
+        assert!(second
+            .requests()
+            .iter()
+            .all(|target| api_name(target) == "getMeetings"));
+        assert_eq!(cluster.server_for("new"), Some("first".to_string()));
+    }
+}
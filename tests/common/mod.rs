@@ -0,0 +1,396 @@
+#![allow(dead_code)]
+//! In-process mock of the BBB API used by the integration tests.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+type Handler = dyn Fn(&str) -> (u16, String) + Send + Sync;
+
+/// Minimal HTTP server answering every request through a handler.
+///
+/// The handler receives the request target (path and query) and returns
+/// the status code and body to send back.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    bodies: Arc<Mutex<Vec<String>>>,
+    peak_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    /// Starts the server on a random local port.
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+    {
+        Self::start_with_delay(Duration::ZERO, handler).await
+    }
+
+    /// Starts the server, delaying every response by `delay`.
+    pub async fn start_with_delay<F>(delay: Duration, handler: F) -> Self
+    where
+        F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Unable to bind mock server");
+        let address = listener.local_addr().expect("Unable to read local address");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let recorded = requests.clone();
+        let recorded_bodies = bodies.clone();
+        let peak = peak_in_flight.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let recorded = recorded.clone();
+                let recorded_bodies = recorded_bodies.clone();
+                let in_flight = in_flight.clone();
+                let peak = peak.clone();
+                tokio::spawn(async move {
+                    let mut buffer = Vec::new();
+                    let mut chunk = [0u8; 1024];
+                    let head_end = loop {
+                        let read = match stream.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => read,
+                        };
+                        buffer.extend_from_slice(&chunk[..read]);
+                        if let Some(position) =
+                            buffer.windows(4).position(|window| window == b"\r\n\r\n")
+                        {
+                            break position + 4;
+                        }
+                    };
+                    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+                    let content_length = head
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    while buffer.len() < head_end + content_length {
+                        match stream.read(&mut chunk).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+                        }
+                    }
+                    recorded_bodies
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(&buffer[head_end..]).to_string());
+                    let target = head
+                        .lines()
+                        .next()
+                        .and_then(|line| line.split_whitespace().nth(1))
+                        .unwrap_or("/")
+                        .to_string();
+                    recorded.lock().unwrap().push(target.clone());
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(current, Ordering::SeqCst);
+                    if !delay.is_zero() {
+                        tokio::time::sleep(delay).await;
+                    }
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    let (status, body) = handler(&target);
+                    let response = format!(
+                        "HTTP/1.1 {} MOCK\r\nContent-Type: text/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        Self {
+            url: format!("http://{}/bigbluebutton/", address),
+            requests,
+            bodies,
+            peak_in_flight,
+        }
+    }
+
+    /// Base URL to pass to `Bigbluebutton::new`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Request targets received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Request bodies received so far, empty for GET requests
+    pub fn bodies(&self) -> Vec<String> {
+        self.bodies.lock().unwrap().clone()
+    }
+
+    /// Highest number of requests handled at the same time
+    pub fn peak_in_flight(&self) -> usize {
+        self.peak_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// Returns the API name of a request target, e.g. `create` for `/bigbluebutton/api/create?...`
+pub fn api_name(target: &str) -> &str {
+    let path = target.split('?').next().unwrap_or(target);
+    path.trim_start_matches("/bigbluebutton/api/")
+}
+
+/// Builds a FAILED response body
+pub fn failed(message_key: &str, message: &str) -> String {
+    format!(
+        "<response><returncode>FAILED</returncode><messageKey>{}</messageKey><message>{}</message></response>",
+        message_key, message
+    )
+}
+
+/// Builds a successful create response body
+pub fn create_response(meeting_id: &str, message_key: &str) -> String {
+    format!(
+        "<response>\
+            <returncode>SUCCESS</returncode>\
+            <meetingID>{0}</meetingID>\
+            <internalMeetingID>{0}-internal</internalMeetingID>\
+            <parentMeetingID>bbb-none</parentMeetingID>\
+            <attendeePW>ap</attendeePW>\
+            <moderatorPW>mp</moderatorPW>\
+            <createTime>1531155809613</createTime>\
+            <voiceBridge>70757</voiceBridge>\
+            <dialNumber>613-555-1234</dialNumber>\
+            <createDate>Mon Jul 09 17:03:29 UTC 2018</createDate>\
+            <hasUserJoined>false</hasUserJoined>\
+            <duration>0</duration>\
+            <hasBeenForciblyEnded>false</hasBeenForciblyEnded>\
+            <messageKey>{1}</messageKey>\
+            <message>message</message>\
+        </response>",
+        meeting_id, message_key
+    )
+}
+
+/// Builds a successful end response body
+pub fn end_response() -> String {
+    "<response>\
+        <returncode>SUCCESS</returncode>\
+        <messageKey>sentEndMeetingRequest</messageKey>\
+        <message>A request to end the meeting was sent.</message>\
+    </response>"
+        .to_string()
+}
+
+/// Builds a successful sendChatMessage response body
+pub fn chat_message_response() -> String {
+    "<response>\
+        <returncode>SUCCESS</returncode>\
+        <messageKey>chatMessageSent</messageKey>\
+        <message>Chat message sent.</message>\
+    </response>"
+        .to_string()
+}
+
+/// Builds the sendChatMessage response for an unknown meeting
+pub fn chat_message_not_found() -> String {
+    failed(
+        "notFound",
+        "We could not find a meeting with that meeting ID",
+    )
+}
+
+/// Builds the response of a join with `redirect=false`
+pub fn join_session_response(session_token: &str) -> String {
+    format!(
+        "<response>\
+            <returncode>SUCCESS</returncode>\
+            <messageKey>successfullyJoined</messageKey>\
+            <message>You have joined successfully.</message>\
+            <meeting_id>m1-internal</meeting_id>\
+            <user_id>w_abc</user_id>\
+            <auth_token>auth</auth_token>\
+            <session_token>{0}</session_token>\
+            <guestStatus>ALLOW</guestStatus>\
+            <url>https://example.com/html5client/join?sessionToken={0}</url>\
+        </response>",
+        session_token
+    )
+}
+
+/// Builds the JSON response of enter
+pub fn enter_response(full_name: &str) -> String {
+    format!(
+        r#"{{"response":{{"returncode":"SUCCESS","fullname":"{}","confname":"Class","meetingID":"m1-internal","externMeetingID":"m1","externUserID":"w_abc","internalUserID":"w_abc","authToken":"auth","role":"VIEWER","guest":"false","guestStatus":"ALLOW","conference":"m1-internal","room":"m1-internal","voicebridge":"70066","dialnumber":"613-555-1234","webvoiceconf":"70066","mode":"LIVE","record":"false","isBreakout":false,"logoutTimer":0,"welcome":"Welcome","logoutUrl":"https://example.com","customdata":[],"metadata":[]}}}}"#,
+        full_name
+    )
+}
+
+/// Builds the JSON error response of enter
+pub fn enter_failed() -> String {
+    r#"{"response":{"returncode":"FAILED","message":"Could not find conference m1.","logoutURL":"https://example.com"}}"#
+        .to_string()
+}
+
+/// Builds a getJoinUrl response body
+pub fn join_url_response(url: &str) -> String {
+    format!(
+        "<response>\
+            <returncode>SUCCESS</returncode>\
+            <messageKey>joinUrlGenerated</messageKey>\
+            <message>Join url generated.</message>\
+            <url>{}</url>\
+        </response>",
+        url
+    )
+}
+
+/// Builds an isMeetingRunning response body
+pub fn running_response(running: bool) -> String {
+    format!(
+        "<response><returncode>SUCCESS</returncode><running>{}</running></response>",
+        running
+    )
+}
+
+/// Attendee rendered into meeting fixtures
+#[derive(Clone, Default)]
+pub struct MockAttendee {
+    pub user_id: &'static str,
+    pub full_name: &'static str,
+    pub presenter: bool,
+    pub video: bool,
+    pub voice: bool,
+}
+
+impl MockAttendee {
+    pub fn new(user_id: &'static str, full_name: &'static str) -> Self {
+        Self {
+            user_id,
+            full_name,
+            ..Default::default()
+        }
+    }
+}
+
+/// Builds the meeting fields shared by getMeetings and getMeetingInfo
+pub fn meeting_fields(meeting_id: &str, attendees: &[MockAttendee]) -> String {
+    let rendered = attendees
+        .iter()
+        .map(|attendee| {
+            format!(
+                "<attendee>\
+                    <userID>{}</userID>\
+                    <fullName>{}</fullName>\
+                    <role>VIEWER</role>\
+                    <isPresenter>{}</isPresenter>\
+                    <isListeningOnly>false</isListeningOnly>\
+                    <hasJoinedVoice>{}</hasJoinedVoice>\
+                    <hasVideo>{}</hasVideo>\
+                    <clientType>HTML5</clientType>\
+                </attendee>",
+                attendee.user_id,
+                attendee.full_name,
+                attendee.presenter,
+                attendee.voice,
+                attendee.video
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<meetingName>{0}</meetingName>\
+        <meetingID>{0}</meetingID>\
+        <internalMeetingID>{0}-internal</internalMeetingID>\
+        <createTime>1531241258036</createTime>\
+        <createDate>Tue Jul 10 16:47:38 UTC 2018</createDate>\
+        <voiceBridge>70066</voiceBridge>\
+        <dialNumber>613-555-1234</dialNumber>\
+        <attendeePW>ap</attendeePW>\
+        <moderatorPW>mp</moderatorPW>\
+        <running>true</running>\
+        <duration>0</duration>\
+        <hasUserJoined>true</hasUserJoined>\
+        <recording>false</recording>\
+        <hasBeenForciblyEnded>false</hasBeenForciblyEnded>\
+        <startTime>1531241258074</startTime>\
+        <endTime>0</endTime>\
+        <participantCount>{1}</participantCount>\
+        <listenerCount>0</listenerCount>\
+        <voiceParticipantCount>0</voiceParticipantCount>\
+        <videoCount>0</videoCount>\
+        <maxUsers>0</maxUsers>\
+        <moderatorCount>0</moderatorCount>\
+        <attendees>{2}</attendees>\
+        <metadata></metadata>\
+        <isBreakout>false</isBreakout>",
+        meeting_id,
+        attendees.len(),
+        rendered
+    )
+}
+
+/// Builds a getMeetingInfo response body
+pub fn meeting_info_response(meeting_id: &str, attendees: &[MockAttendee]) -> String {
+    format!(
+        "<response><returncode>SUCCESS</returncode>{}</response>",
+        meeting_fields(meeting_id, attendees)
+    )
+}
+
+/// Builds a getMeetings response body
+pub fn meetings_response(meetings: &[(&str, Vec<MockAttendee>)]) -> String {
+    let rendered = meetings
+        .iter()
+        .map(|(meeting_id, attendees)| {
+            format!(
+                "<meeting>{}</meeting>",
+                meeting_fields(meeting_id, attendees)
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<response><returncode>SUCCESS</returncode><meetings>{}</meetings></response>",
+        rendered
+    )
+}
+
+/// Builds a getRecordings response body for `(record_id, meeting_id, published)` entries
+pub fn recordings_response(recordings: &[(&str, &str, bool)]) -> String {
+    let rendered = recordings
+        .iter()
+        .map(|(record_id, meeting_id, published)| {
+            format!(
+                "<recording>\
+                    <recordID>{0}</recordID>\
+                    <meetingID>{1}</meetingID>\
+                    <internalMeetingID>{0}</internalMeetingID>\
+                    <name>{1} name</name>\
+                    <published>{2}</published>\
+                    <state>{3}</state>\
+                    <startTime>1530718721124</startTime>\
+                    <endTime>1530718810837</endTime>\
+                    <participants>3</participants>\
+                    <metadata><isBreakout>false</isBreakout></metadata>\
+                    <playback><format><type>presentation</type><url>https://example.com/playback/{0}</url></format></playback>\
+                </recording>",
+                record_id,
+                meeting_id,
+                published,
+                if *published { "published" } else { "unpublished" }
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<response><returncode>SUCCESS</returncode><recordings>{}</recordings></response>",
+        rendered
+    )
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "config")]
+
+#[cfg(test)]
+mod test {
+    use bigbluebutton::config::{Config, RetryConfig, ServerConfig};
+    use bigbluebutton::error::ConfigError;
+    use bigbluebutton::{Bigbluebutton, ChecksumAlgorithm};
+
+    #[test]
+    fn toml_and_json_settings() {
+        let toml = r#"
+            url = "https://example.com/bigbluebutton/"
+            secret = "secret"
+            fallback_secrets = ["old"]
+            checksum_algorithm = "sha512"
+            timeout_ms = 2500
+
+            [retry]
+            max_attempts = 5
+
+            [[servers]]
+            id = "bbb-1"
+            url = "https://bbb-1.example.com/bigbluebutton/"
+            secret = "one"
+            weight = 2
+
+            [[servers]]
+            id = "bbb-2"
+            url = "https://bbb-2.example.com/bigbluebutton/"
+            secret = "two"
+        "#;
+        let json = r#"{
+            "url": "https://example.com/bigbluebutton/",
+            "secret": "secret",
+            "fallback_secrets": ["old"],
+            "checksum_algorithm": "sha512",
+            "timeout_ms": 2500,
+            "retry": {"max_attempts": 5},
+            "servers": [
+                {"id": "bbb-1", "url": "https://bbb-1.example.com/bigbluebutton/", "secret": "one", "weight": 2},
+                {"id": "bbb-2", "url": "https://bbb-2.example.com/bigbluebutton/", "secret": "two"}
+            ]
+        }"#;
+
+        let config = Config::from_toml(toml).expect("Unable to parse TOML");
+        assert_eq!(
+            config,
+            Config::from_json(json).expect("Unable to parse JSON")
+        );
+        assert_eq!(config.checksum_algorithm, Some(ChecksumAlgorithm::Sha512));
+        assert_eq!(
+            config.retry,
+            Some(RetryConfig {
+                max_attempts: Some(5),
+                ..RetryConfig::default()
+            })
+        );
+        assert_eq!(
+            config.servers[1],
+            ServerConfig {
+                id: "bbb-2".to_string(),
+                url: "https://bbb-2.example.com/bigbluebutton/".to_string(),
+                secret: "two".to_string(),
+                weight: 1,
+            }
+        );
+
+        let client = config.client().expect("Unable to build client");
+        let url = client
+            .generate_url("getMeetings", vec![("a", "1")])
+            .expect("Unable to generate url");
+        assert_eq!(
+            url.rsplit_once("checksum=").map(|(_, c)| c.len()),
+            Some(128)
+        );
+
+        let cluster = config.cluster().expect("Unable to build cluster");
+        assert_eq!(cluster.server_ids(), vec!["bbb-1", "bbb-2"]);
+    }
+
+    #[test]
+    fn bbb_conf_secret_output() {
+        let output = "
+
+    URL: https://bbb.example.com/bigbluebutton/
+    Secret: 330a8b08c3b4c61533e1d0c5ce1ac88f
+
+      Link to the API-Mate:
+      https://mconf.github.io/api-mate/#server=https://bbb.example.com/bigbluebutton/&sharedSecret=330a8b08c3b4c61533e1d0c5ce1ac88f
+";
+        let config = Config::from_bbb_conf(output).expect("Unable to parse output");
+        assert_eq!(
+            config.url.as_deref(),
+            Some("https://bbb.example.com/bigbluebutton/")
+        );
+        assert_eq!(
+            config.secret.as_deref(),
+            Some("330a8b08c3b4c61533e1d0c5ce1ac88f")
+        );
+        assert_eq!(
+            Config::from_bbb_conf("nothing here"),
+            Err(ConfigError::Missing("URL".to_string()))
+        );
+    }
+
+    #[test]
+    fn bbb_web_properties() {
+        let properties = "
+# The URL of your BigBlueButton server
+bigbluebutton.web.serverURL=https://bbb.example.com/
+securitySalt = 330a8b08c3b4c61533e1d0c5ce1ac88f
+supportedChecksumAlgorithms=sha1,sha256,sha384
+";
+        let config = Config::from_properties(properties).expect("Unable to parse properties");
+        assert_eq!(
+            config.url.as_deref(),
+            Some("https://bbb.example.com/bigbluebutton/")
+        );
+        assert_eq!(
+            config.secret.as_deref(),
+            Some("330a8b08c3b4c61533e1d0c5ce1ac88f")
+        );
+        assert_eq!(config.checksum_algorithm, Some(ChecksumAlgorithm::Sha384));
+
+        assert!(matches!(
+            Config::from_properties("bigbluebutton.web.serverURL=https://bbb.example.com"),
+            Err(ConfigError::Missing(key)) if key == "securitySalt"
+        ));
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let path = std::env::temp_dir().join(format!("bbb-config-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "url = \"https://file.example.com/bigbluebutton/\"\nsecret = \"file\"\n",
+        )
+        .expect("Unable to write config");
+
+        std::env::set_var("BBB_CONFIG", &path);
+        std::env::set_var("BBB_SECRET", "env");
+        std::env::set_var("BBB_CHECKSUM_ALGORITHM", "sha256");
+        let config = Config::from_env().expect("Unable to read environment");
+        let client = Bigbluebutton::from_env().expect("Unable to build client");
+        std::env::remove_var("BBB_CONFIG");
+        std::env::remove_var("BBB_SECRET");
+        std::env::remove_var("BBB_CHECKSUM_ALGORITHM");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(
+            config.url.as_deref(),
+            Some("https://file.example.com/bigbluebutton/")
+        );
+        assert_eq!(config.secret.as_deref(), Some("env"));
+        let url = client
+            .generate_url("getMeetings", vec![("a", "1")])
+            .expect("Unable to generate url");
+        assert!(url.starts_with("https://file.example.com/bigbluebutton/api/getMeetings"));
+        assert_eq!(url.rsplit_once("checksum=").map(|(_, c)| c.len()), Some(64));
+    }
+}
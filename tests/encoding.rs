@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod test {
+    use bigbluebutton::administration::JoinMeetingRequest;
+    use bigbluebutton::{Bigbluebutton, ChecksumAlgorithm};
+    use proptest::prelude::*;
+    use sha2::Digest;
+
+    const SECRET: &str = "8cd8ef52e8e101574e400365b55e11a6";
+
+    /// Output of Java's `URLEncoder.encode(value, "UTF-8")`, which bbb-web uses to rebuild the
+    /// query it checks the checksum against
+    const JAVA_URL_ENCODER: &[(&str, &str)] = &[
+        ("Test Meeting", "Test+Meeting"),
+        ("~", "%7E"),
+        ("*", "*"),
+        (".-_", ".-_"),
+        ("a+b", "a%2Bb"),
+        ("'()!", "%27%28%29%21"),
+        ("Zo\u{eb} Smith", "Zo%C3%AB+Smith"),
+        ("\u{e9}", "%C3%A9"),
+        ("e\u{301}", "e%CC%81"),
+        ("\u{65e5}\u{672c}\u{8a9e}", "%E6%97%A5%E6%9C%AC%E8%AA%9E"),
+        ("\u{1f600}", "%F0%9F%98%80"),
+        ("100% & more", "100%25+%26+more"),
+        ("/?#[]@=", "%2F%3F%23%5B%5D%40%3D"),
+        ("tab\tline\n", "tab%09line%0A"),
+        ("<b>\"quoted\"</b>", "%3Cb%3E%22quoted%22%3C%2Fb%3E"),
+    ];
+
+    fn client(algorithm: ChecksumAlgorithm) -> Bigbluebutton {
+        Bigbluebutton::builder("https://example.com/bigbluebutton/", SECRET)
+            .checksum_algorithm(algorithm)
+            .build()
+    }
+
+    #[test]
+    fn values_are_encoded_like_java_url_encoder() {
+        for (value, expected) in JAVA_URL_ENCODER {
+            assert_eq!(
+                Bigbluebutton::encode_query(vec![("value", value)]),
+                format!("value={}", expected),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn special_characters_are_encoded_like_java() {
+        let url = client(ChecksumAlgorithm::Sha256)
+            .generate_url("create", vec![("name", "a b+c~d*e'f(g)h!é")])
+            .expect("Unable to generate url");
+        assert!(url.contains("name=a+b%2Bc%7Ed*e%27f%28g%29h%21%C3%A9&checksum="));
+    }
+
+    #[test]
+    fn empty_query_has_no_separator() {
+        let url = client(ChecksumAlgorithm::Sha256)
+            .generate_url("getMeetings", Vec::<(&str, &str)>::new())
+            .expect("Unable to generate url");
+        assert!(url.contains("/api/getMeetings?checksum="));
+    }
+
+    #[test]
+    #[cfg(feature = "webhook")]
+    fn documented_create_checksum() {
+        // example of the BBB API documentation
+        let client = Bigbluebutton::builder(
+            "https://example.com/bigbluebutton/",
+            "639259d4-9dd8-4b25-bf01-95f9567eaf4b",
+        )
+        .checksum_algorithm(ChecksumAlgorithm::Sha1)
+        .build();
+        let url = client
+            .generate_url(
+                "create",
+                vec![
+                    ("name", "Test Meeting"),
+                    ("meetingID", "abc123"),
+                    ("attendeePW", "111222"),
+                    ("moderatorPW", "333444"),
+                ],
+            )
+            .expect("Unable to generate url");
+        assert_eq!(
+            url,
+            "https://example.com/bigbluebutton/api/create?name=Test+Meeting&meetingID=abc123\
+            &attendeePW=111222&moderatorPW=333444&checksum=1fcbb0c4fc1f039f73aa6d697d2db9ba7f803f17"
+        );
+    }
+
+    // Checksums below were computed like bbb-web: `URLEncoder.encode` of every value and a
+    // `MessageDigest` over `api + query + secret`.
+
+    const JOIN_QUERY: &str = "fullName=Zo%C3%AB+Smith%7E*&meetingID=meeting+1&password=ap\
+        &userID=%E6%97%A5%E6%9C%AC%E8%AA%9E+%F0%9F%98%80";
+
+    fn join_url(algorithm: ChecksumAlgorithm) -> String {
+        client(algorithm)
+            .generate_url(
+                "join",
+                vec![
+                    ("fullName", "Zo\u{eb} Smith~*"),
+                    ("meetingID", "meeting 1"),
+                    ("password", "ap"),
+                    ("userID", "\u{65e5}\u{672c}\u{8a9e} \u{1f600}"),
+                ],
+            )
+            .expect("Unable to generate url")
+    }
+
+    #[test]
+    fn unicode_join_checksum_matches_bbb_web() {
+        assert_eq!(
+            join_url(ChecksumAlgorithm::Sha256),
+            format!(
+                "https://example.com/bigbluebutton/api/join?{}&checksum={}",
+                JOIN_QUERY, "076719e27ed1c80653cbe51728de0c46935958be96b811e5703f6c4c462751fd"
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "webhook")]
+    fn unicode_join_sha1_checksum_matches_bbb_web() {
+        assert_eq!(
+            join_url(ChecksumAlgorithm::Sha1),
+            format!(
+                "https://example.com/bigbluebutton/api/join?{}&checksum={}",
+                JOIN_QUERY, "f4e3e21482ef192cbe67b52d32e4f11d9dc6298c"
+            )
+        );
+    }
+
+    #[test]
+    fn typed_join_url_matches_bbb_web() {
+        let mut request = JoinMeetingRequest::new("meeting 1", "Zo\u{eb} Smith~*");
+        request.set_password(Some("ap".to_string()));
+        let url = client(ChecksumAlgorithm::Sha256)
+            .join_url(&request)
+            .expect("Unable to generate url");
+        assert_eq!(
+            url,
+            "https://example.com/bigbluebutton/api/join?fullName=Zo%C3%AB+Smith%7E*\
+            &meetingID=meeting+1&password=ap\
+            &checksum=51a9b011da5feff4f3383e444ee91327122febc3dad17fd47fb98d85f649c7b7"
+        );
+    }
+
+    // Randomized checks against `url::form_urlencoded`, an independent implementation of the
+    // same encoding: WHATWG form encoding and `URLEncoder` both keep `A-Z a-z 0-9 * - . _`,
+    // turn spaces into `+` and percent-encode every other UTF-8 byte in upper case.
+
+    fn reference_query(params: &[(String, String)]) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish()
+    }
+
+    fn reference_checksum(algorithm: ChecksumAlgorithm, payload: &str) -> String {
+        match algorithm {
+            #[cfg(feature = "webhook")]
+            ChecksumAlgorithm::Sha1 => hex::encode(sha1::Sha1::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha256 => hex::encode(sha2::Sha256::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha384 => hex::encode(sha2::Sha384::digest(payload.as_bytes())),
+            ChecksumAlgorithm::Sha512 => hex::encode(sha2::Sha512::digest(payload.as_bytes())),
+        }
+    }
+
+    fn algorithms() -> Vec<ChecksumAlgorithm> {
+        vec![
+            #[cfg(feature = "webhook")]
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+            ChecksumAlgorithm::Sha384,
+            ChecksumAlgorithm::Sha512,
+        ]
+    }
+
+    fn params() -> impl Strategy<Value = Vec<(String, String)>> {
+        prop::collection::vec(("[a-zA-Z]{1,16}", any::<String>()), 0..6)
+    }
+
+    proptest! {
+        #[test]
+        fn query_matches_form_urlencoded(params in params()) {
+            prop_assert_eq!(Bigbluebutton::encode_query(params.clone()), reference_query(&params));
+        }
+
+        #[test]
+        fn query_decodes_to_the_original_values(params in params()) {
+            let query = Bigbluebutton::encode_query(params.clone());
+            let decoded = url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect::<Vec<_>>();
+            prop_assert_eq!(decoded, params);
+        }
+    }
+
+    // clients are built once, building one for every case takes most of the run time
+
+    #[test]
+    fn generated_url_checksum_matches_reference() {
+        let clients = algorithms()
+            .into_iter()
+            .map(|algorithm| (algorithm, client(algorithm)))
+            .collect::<Vec<_>>();
+        proptest::test_runner::TestRunner::default()
+            .run(&params(), |params| {
+                let query = reference_query(&params);
+                for (algorithm, client) in &clients {
+                    let url = client
+                        .generate_url("create", params.clone())
+                        .expect("Unable to generate url");
+                    let checksum =
+                        reference_checksum(*algorithm, &format!("create{}{}", query, SECRET));
+                    let expected = if query.is_empty() {
+                        format!(
+                            "https://example.com/bigbluebutton/api/create?checksum={}",
+                            checksum
+                        )
+                    } else {
+                        format!(
+                            "https://example.com/bigbluebutton/api/create?{}&checksum={}",
+                            query, checksum
+                        )
+                    };
+                    prop_assert_eq!(url, expected);
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn typed_join_url_checksum_matches_reference() {
+        let client = client(ChecksumAlgorithm::Sha256);
+        proptest::test_runner::TestRunner::default()
+            .run(
+                &(any::<String>(), any::<String>()),
+                |(meeting_id, full_name)| {
+                    let request = JoinMeetingRequest::new(&meeting_id, &full_name);
+                    let url = client.join_url(&request).expect("Unable to generate url");
+                    let query = reference_query(&[
+                        ("fullName".to_string(), full_name),
+                        ("meetingID".to_string(), meeting_id),
+                    ]);
+                    let checksum = reference_checksum(
+                        ChecksumAlgorithm::Sha256,
+                        &format!("join{}{}", query, SECRET),
+                    );
+                    prop_assert_eq!(
+                        url,
+                        format!(
+                            "https://example.com/bigbluebutton/api/join?{}&checksum={}",
+                            query, checksum
+                        )
+                    );
+                    Ok(())
+                },
+            )
+            .unwrap();
+    }
+}
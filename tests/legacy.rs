@@ -0,0 +1,82 @@
+#![cfg(feature = "legacy")]
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{api_name, failed, MockServer};
+    use bigbluebutton::administration::legacy::SetConfigXmlRequest;
+    use bigbluebutton::error::BBBError;
+    use bigbluebutton::Bigbluebutton;
+    use sha2::Digest;
+
+    const CONFIG_XML: &str = "<config><localeversion>0.9.0</localeversion></config>";
+
+    #[tokio::test]
+    async fn get_default_config_xml() {
+        let server = MockServer::start(|_| (200, CONFIG_XML.to_string())).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let config_xml = client
+            .get_default_config_xml()
+            .await
+            .expect("Unable to fetch config.xml");
+        assert_eq!(config_xml, CONFIG_XML);
+        assert!(server.requests()[0].contains("getDefaultConfigXML?checksum="));
+    }
+
+    #[tokio::test]
+    async fn get_default_config_xml_failure() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                failed(
+                    "checksumError",
+                    "You did not pass the checksum security check",
+                ),
+            )
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let error = client
+            .get_default_config_xml()
+            .await
+            .expect_err("checksum should be rejected");
+        assert_eq!(
+            error.downcast_ref::<BBBError>().unwrap().message_key,
+            "checksumError"
+        );
+    }
+
+    #[tokio::test]
+    async fn set_config_xml_posts_signed_form() {
+        let server = MockServer::start(|target| match api_name(target) {
+            "setConfigXML" => (
+                200,
+                "<response><returncode>SUCCESS</returncode><configToken>token1</configToken></response>"
+                    .to_string(),
+            ),
+            _ => (404, String::new()),
+        })
+        .await;
+        let client = Bigbluebutton::builder(server.url(), "secret")
+            .checksum_algorithm(bigbluebutton::ChecksumAlgorithm::Sha256)
+            .build();
+
+        let response = client
+            .set_config_xml(&SetConfigXmlRequest::new("meeting 1", CONFIG_XML))
+            .await
+            .expect("Unable to parse SetConfigXmlResponse");
+        assert_eq!(response.config_token(), "token1");
+
+        assert!(server.requests()[0].ends_with("/api/setConfigXML"));
+        let query = "configXML=%3Cconfig%3E%3Clocaleversion%3E0.9.0%3C%2Flocaleversion%3E%3C%2Fconfig%3E&meetingID=meeting+1";
+        let checksum = hex::encode(sha2::Sha256::digest(
+            format!("setConfigXML{}secret", query).as_bytes(),
+        ));
+        assert_eq!(
+            server.bodies()[0],
+            format!("{}&checksum={}", query, checksum)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "metrics")]
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{failed, meetings_response, MockServer};
+    use bigbluebutton::monitoring::{GetMeetingInfoRequest, GetMeetingsRequest};
+    use bigbluebutton::Bigbluebutton;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use metrics_util::MetricKind;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn dispatch_records_requests_and_latency() {
+        let server = MockServer::start(|target| {
+            if target.contains("getMeetingInfo") {
+                (
+                    200,
+                    failed(
+                        "notFound",
+                        "We could not find a meeting with that meeting ID",
+                    ),
+                )
+            } else {
+                (200, meetings_response(&[]))
+            }
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        client
+            .get_meetings(&GetMeetingsRequest::new())
+            .await
+            .expect("Unable to parse GetMeetingsResponse");
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("missing".to_string());
+        client
+            .get_meeting_info(&request)
+            .await
+            .expect_err("Meeting should not be found");
+
+        let mut series = Vec::new();
+        for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+            let (kind, key) = key.into_parts();
+            let mut labels = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect::<Vec<_>>();
+            labels.sort();
+            let value = match (kind, value) {
+                (MetricKind::Counter, DebugValue::Counter(count)) => count.to_string(),
+                (MetricKind::Histogram, DebugValue::Histogram(samples)) => {
+                    format!("{} samples", samples.len())
+                }
+                (kind, value) => panic!("unexpected metric {:?} {:?}", kind, value),
+            };
+            series.push(format!("{}{{{}}} {}", key.name(), labels.join(","), value));
+        }
+        series.sort();
+
+        assert_eq!(
+            series,
+            vec![
+                "bbb_api_request_duration_seconds{api=getMeetingInfo} 1 samples",
+                "bbb_api_request_duration_seconds{api=getMeetings} 1 samples",
+                "bbb_api_requests_total{api=getMeetingInfo,message_key=notFound,result=failed} 1",
+                "bbb_api_requests_total{api=getMeetings,message_key=,result=success} 1",
+            ]
+        );
+    }
+}
@@ -1,15 +1,30 @@
+mod common;
+
 #[cfg(test)]
 mod test {
+    use super::common::{
+        failed, meeting_info_response, meetings_response, running_response, MockAttendee,
+        MockServer,
+    };
     use bigbluebutton::administration::CreateMeetingRequest;
-    use bigbluebutton::monitoring::{GetMeetingInfoRequest, GetMeetingsRequest};
+    use bigbluebutton::error::WaitError;
+    use bigbluebutton::monitoring::dashboard::DashboardAccess;
+    use bigbluebutton::monitoring::wait::WaitOptions;
+    use bigbluebutton::monitoring::watch::MeetingEvent;
+    use bigbluebutton::monitoring::{
+        GetMeetingInfoRequest, GetMeetingsRequest, IsMeetingRunningRequest,
+    };
     use bigbluebutton::Bigbluebutton;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
 
     #[tokio::test]
     #[ignore]
     async fn get_meeting_info() {
-        let bbb_url = std::env::var("BBB_URL").expect("BBB_URL is not set");
-        let bbb_secret = std::env::var("BBB_SECRET").expect("BBB_SECRET is not set");
-        let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+        let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
         let mut request = CreateMeetingRequest::new("14");
         request.set_moderator_pw(Some("modp".to_string()));
@@ -30,9 +45,7 @@ mod test {
     #[tokio::test]
     #[ignore]
     async fn get_meetings() {
-        let bbb_url = std::env::var("BBB_URL").expect("BBB_URL is not set");
-        let bbb_secret = std::env::var("BBB_SECRET").expect("BBB_SECRET is not set");
-        let client = Bigbluebutton::new(&bbb_url, &bbb_secret);
+        let client = Bigbluebutton::from_env().expect("BBB_URL and BBB_SECRET must be set");
 
         let mut request = CreateMeetingRequest::new("15");
         request.set_moderator_pw(Some("modp".to_string()));
@@ -53,4 +66,360 @@ mod test {
             .expect("Unable to parse GetMeetingsResponse");
         assert_ne!(response.meetings().len(), 0);
     }
+
+    fn fast_options() -> WaitOptions {
+        let mut options = WaitOptions::new();
+        options.set_interval(Duration::from_millis(10));
+        options.set_timeout(Some(Duration::from_secs(5)));
+        options
+    }
+
+    #[tokio::test]
+    async fn wait_for_running_polls_until_running() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let server = MockServer::start(move |_| {
+            let poll = counter.fetch_add(1, Ordering::SeqCst);
+            (200, running_response(poll >= 2))
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        client
+            .wait_for_running(&IsMeetingRunningRequest::new("1"), &fast_options())
+            .await
+            .expect("Meeting should be running");
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn wait_for_end_times_out() {
+        let server = MockServer::start(|_| (200, running_response(true))).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut options = fast_options();
+        options.set_timeout(Some(Duration::from_millis(50)));
+        let error = client
+            .wait_for_end(&IsMeetingRunningRequest::new("1"), &options)
+            .await
+            .expect_err("Wait should time out");
+        assert_eq!(error.downcast_ref::<WaitError>(), Some(&WaitError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn wait_can_be_cancelled() {
+        let server = MockServer::start(|_| (200, running_response(false))).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let token = CancellationToken::new();
+        let mut options = fast_options();
+        options.set_cancellation(Some(token.clone()));
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            token.cancel();
+        });
+        let error = client
+            .wait_for_running(&IsMeetingRunningRequest::new("1"), &options)
+            .await
+            .expect_err("Wait should be cancelled");
+        canceller.await.unwrap();
+        assert_eq!(
+            error.downcast_ref::<WaitError>(),
+            Some(&WaitError::Cancelled)
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_participants_ignores_missing_meeting() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let server = MockServer::start(move |_| match counter.fetch_add(1, Ordering::SeqCst) {
+            0 => (
+                200,
+                failed(
+                    "notFound",
+                    "We could not find a meeting with that meeting ID",
+                ),
+            ),
+            1 => (
+                200,
+                meeting_info_response("1", &[MockAttendee::new("u1", "Alice")]),
+            ),
+            _ => (
+                200,
+                meeting_info_response(
+                    "1",
+                    &[
+                        MockAttendee::new("u1", "Alice"),
+                        MockAttendee::new("u2", "Bob"),
+                    ],
+                ),
+            ),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("1".to_string());
+        let response = client
+            .wait_for_participants(&request, 2, &fast_options())
+            .await
+            .expect("Participants should join");
+        assert_eq!(response.attendees().len(), 2);
+    }
+
+    async fn collect_events(snapshots: Vec<String>, count: usize) -> Vec<MeetingEvent> {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start(move |_| {
+            let poll = polls.fetch_add(1, Ordering::SeqCst);
+            (200, snapshots[poll.min(snapshots.len() - 1)].clone())
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let events = client.watch_meetings(Duration::from_millis(5));
+        futures::pin_mut!(events);
+        let mut collected = Vec::new();
+        while collected.len() < count {
+            let event = events
+                .next()
+                .await
+                .expect("Stream should not end")
+                .expect("Snapshot should parse");
+            collected.push(event);
+        }
+        collected
+    }
+
+    #[tokio::test]
+    async fn watch_meetings_reports_meeting_lifecycle() {
+        let snapshots = vec![
+            meetings_response(&[]),
+            meetings_response(&[("room", vec![MockAttendee::new("u1", "Alice")])]),
+            meetings_response(&[]),
+        ];
+
+        let events = collect_events(snapshots, 3).await;
+        assert_eq!(
+            events,
+            vec![
+                MeetingEvent::MeetingStarted {
+                    meeting_id: "room".to_string(),
+                    internal_meeting_id: "room-internal".to_string(),
+                    meeting_name: "room".to_string(),
+                },
+                MeetingEvent::UserJoined {
+                    meeting_id: "room".to_string(),
+                    user_id: "u1".to_string(),
+                    full_name: "Alice".to_string(),
+                    role: "VIEWER".to_string(),
+                },
+                MeetingEvent::MeetingEnded {
+                    meeting_id: "room".to_string(),
+                    internal_meeting_id: "room-internal".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_meetings_reports_attendee_changes() {
+        let alice = MockAttendee::new("u1", "Alice");
+        let bob = MockAttendee::new("u2", "Bob");
+        let alice_presenting = MockAttendee {
+            presenter: true,
+            video: true,
+            voice: true,
+            ..alice.clone()
+        };
+        let snapshots = vec![
+            meetings_response(&[("room", vec![alice.clone(), bob.clone()])]),
+            meetings_response(&[("room", vec![alice_presenting])]),
+            meetings_response(&[("room", vec![alice])]),
+        ];
+
+        let events = collect_events(snapshots, 7).await;
+        let meeting_id = "room".to_string();
+        let user_id = "u1".to_string();
+        assert_eq!(
+            events,
+            vec![
+                MeetingEvent::UserLeft {
+                    meeting_id: meeting_id.clone(),
+                    user_id: "u2".to_string(),
+                    full_name: "Bob".to_string(),
+                },
+                MeetingEvent::VideoStarted {
+                    meeting_id: meeting_id.clone(),
+                    user_id: user_id.clone(),
+                },
+                MeetingEvent::VoiceJoined {
+                    meeting_id: meeting_id.clone(),
+                    user_id: user_id.clone(),
+                },
+                MeetingEvent::PresenterChanged {
+                    meeting_id: meeting_id.clone(),
+                    user_id: Some(user_id.clone()),
+                },
+                MeetingEvent::VideoStopped {
+                    meeting_id: meeting_id.clone(),
+                    user_id: user_id.clone(),
+                },
+                MeetingEvent::VoiceLeft {
+                    meeting_id: meeting_id.clone(),
+                    user_id: user_id.clone(),
+                },
+                MeetingEvent::PresenterChanged {
+                    meeting_id: meeting_id.clone(),
+                    user_id: None,
+                },
+            ]
+        );
+    }
+
+    const DASHBOARD: &str = r#"{
+        "intId": "meeting-internal",
+        "extId": "meeting",
+        "name": "Math 101",
+        "createdOn": 1000,
+        "endedOn": 601000,
+        "users": {
+            "alice": {
+                "userKey": "alice",
+                "extId": "u1",
+                "name": "Alice",
+                "isModerator": true,
+                "intIds": {
+                    "w_1": { "intId": "w_1", "registeredOn": 1000, "leftOn": 61000 },
+                    "w_2": { "intId": "w_2", "registeredOn": 121000, "leftOn": 0 }
+                },
+                "answers": {},
+                "talk": { "totalTime": 30000, "lastTalkStartedOn": 0 },
+                "emojis": [{ "name": "happy", "sentOn": 2000 }],
+                "reactions": [{ "reaction": "👍", "sentOn": 3000 }],
+                "raiseHand": [4000],
+                "webcams": [{ "startedOn": 1000, "stoppedOn": 11000 }],
+                "totalOfMessages": 3
+            },
+            "bob": {
+                "userKey": "bob",
+                "name": "Bob",
+                "isModerator": false,
+                "intIds": { "w_3": { "intId": "w_3", "registeredOn": 1000, "leftOn": 301000 } },
+                "answers": { "poll-1": "Yes", "poll-2": ["A", "B"] },
+                "emojis": [{ "name": "raiseHand", "sentOn": 5000 }],
+                "totalOfMessages": 1
+            }
+        },
+        "polls": {
+            "poll-1": {
+                "pollId": "poll-1",
+                "type": "YN",
+                "question": "Ready?",
+                "options": ["Yes", "No"],
+                "anonymous": false,
+                "createdAt": 4000
+            }
+        },
+        "screenshares": [{ "startedOn": 1000, "stoppedOn": 2000 }]
+    }"#;
+
+    #[tokio::test]
+    async fn learning_dashboard_access_from_meeting_info() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                meeting_info_response("meeting", &[]).replace(
+                    "</response>",
+                    "<learningDashboardAccessToken>token1</learningDashboardAccessToken></response>",
+                ),
+            )
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let mut request = GetMeetingInfoRequest::new();
+        request.meeting_id = Some("meeting".to_string());
+        let info = client
+            .get_meeting_info(&request)
+            .await
+            .expect("Unable to parse GetMeetingInfoResponse");
+        let access = DashboardAccess::from_meeting_info(&info).expect("dashboard is enabled");
+        assert_eq!(access, DashboardAccess::new("meeting-internal", "token1"));
+
+        let addr = server.url().trim_end_matches("bigbluebutton/");
+        assert_eq!(
+            client.learning_dashboard_url(&access).unwrap(),
+            format!(
+                "{}learning-analytics-dashboard/?meeting=meeting-internal&report=token1",
+                addr
+            )
+        );
+        assert_eq!(
+            client.learning_dashboard_data_url(&access).unwrap(),
+            format!(
+                "{}learning-analytics-dashboard/meeting-internal/token1/learning_dashboard_data.json",
+                addr
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn learning_dashboard_summaries() {
+        let server = MockServer::start(|target| {
+            if target.ends_with("/meeting-internal/token1/learning_dashboard_data.json") {
+                (200, DASHBOARD.to_string())
+            } else {
+                (404, String::new())
+            }
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let dashboard = client
+            .get_learning_dashboard(&DashboardAccess::new("meeting-internal", "token1"))
+            .await
+            .expect("Unable to parse LearningDashboard");
+        assert_eq!(dashboard.name(), "Math 101");
+        assert_eq!(dashboard.polls()["poll-1"].options(), &["Yes", "No"]);
+        assert_eq!(dashboard.users()["bob"].answers()["poll-2"], ["A", "B"]);
+
+        let summaries = dashboard.summaries();
+        assert_eq!(summaries.len(), 2);
+
+        let alice = &summaries[0];
+        assert_eq!(alice.name(), "Alice");
+        assert!(alice.moderator());
+        assert_eq!(alice.online_time(), &Duration::from_secs(60 + 480));
+        assert_eq!(alice.talk_time(), &Duration::from_secs(30));
+        assert_eq!(alice.webcam_time(), &Duration::from_secs(10));
+        assert_eq!(*alice.messages(), 3);
+        assert_eq!(*alice.raised_hands(), 1);
+        assert_eq!(*alice.emojis(), 2);
+        assert_eq!(*alice.poll_answers(), 0);
+
+        let bob = &summaries[1];
+        assert_eq!(bob.online_time(), &Duration::from_secs(300));
+        assert_eq!(*bob.raised_hands(), 1);
+        assert_eq!(*bob.emojis(), 0);
+        assert_eq!(*bob.poll_answers(), 2);
+    }
+
+    #[tokio::test]
+    async fn learning_dashboard_rejects_unknown_token() {
+        let server = MockServer::start(|_| (404, String::new())).await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let error = client
+            .get_learning_dashboard(&DashboardAccess::new("meeting-internal", "wrong"))
+            .await
+            .expect_err("unknown token should fail");
+        assert_eq!(
+            error
+                .downcast_ref::<bigbluebutton::error::HttpError>()
+                .unwrap()
+                .status,
+            404
+        );
+    }
 }
@@ -0,0 +1,117 @@
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{api_name, recordings_response, MockServer};
+    use bigbluebutton::recording::{GetRecordingsRequest, UpdateRecordingsRequest};
+    use bigbluebutton::Bigbluebutton;
+
+    #[tokio::test]
+    async fn get_recordings() {
+        let server = MockServer::start(|target| match api_name(target) {
+            "getRecordings" if target.contains("meetingID=empty") => {
+                (200, recordings_response(&[]))
+            }
+            _ => (
+                200,
+                recordings_response(&[("r1", "m1", true), ("r2", "m1", false)]),
+            ),
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let response = client
+            .get_recordings(&GetRecordingsRequest::new())
+            .await
+            .expect("Unable to parse GetRecordingsResponse");
+        assert_eq!(response.recordings().len(), 2);
+        assert_eq!(response.recordings()[0].record_id(), "r1");
+        assert!(!response.recordings()[1].published());
+        assert_eq!(
+            response.recordings()[0].playback_url(),
+            Some("https://example.com/playback/r1")
+        );
+        assert_eq!(response.recordings()[0].metadata()["isBreakout"], "false");
+
+        let mut request = GetRecordingsRequest::new();
+        request.meeting_id = Some("empty".to_string());
+        let response = client
+            .get_recordings(&request)
+            .await
+            .expect("Unable to parse GetRecordingsResponse");
+        assert!(response.recordings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_recordings_sends_metadata() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                "<response><returncode>SUCCESS</returncode><updated>true</updated></response>"
+                    .to_string(),
+            )
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let request = UpdateRecordingsRequest::new("r1").meta("presenter", "Alice Smith");
+        let response = client
+            .update_recordings(&request)
+            .await
+            .expect("Unable to parse UpdateRecordingsResponse");
+        assert!(response.updated());
+        assert!(server.requests()[0].contains("recordID=r1&meta_presenter=Alice+Smith&checksum="));
+    }
+
+    #[tokio::test]
+    async fn get_recordings_parses_playback_and_metadata() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                "<response><returncode>SUCCESS</returncode><recordings>\
+                    <recording>\
+                        <recordID>r1</recordID><meetingID>m1</meetingID><name>m1</name>\
+                        <published>true</published>\
+                        <startTime>1530718721124</startTime><endTime>1530718810837</endTime>\
+                        <metadata><meetingName>Weekly</meetingName><presenter>Alice</presenter></metadata>\
+                        <playback>\
+                            <format><type>presentation</type><url>https://example.com/p/r1</url>\
+                                <processingTime>7177</processingTime><length>3</length></format>\
+                            <format><type>video</type><url>https://example.com/v/r1</url></format>\
+                        </playback>\
+                    </recording>\
+                    <recording>\
+                        <recordID>r2</recordID><meetingID>m2</meetingID><name>m2</name>\
+                        <published>false</published>\
+                        <startTime>1530718721124</startTime><endTime>1530718810837</endTime>\
+                        <metadata></metadata><playback></playback>\
+                    </recording>\
+                </recordings></response>"
+                    .to_string(),
+            )
+        })
+        .await;
+        let client = Bigbluebutton::new(server.url(), "secret");
+
+        let response = client
+            .get_recordings(&GetRecordingsRequest::new())
+            .await
+            .expect("Unable to parse GetRecordingsResponse");
+        let recording = &response.recordings()[0];
+        assert_eq!(recording.metadata()["meetingName"], "Weekly");
+        assert_eq!(recording.metadata()["presenter"], "Alice");
+        assert_eq!(recording.playback().len(), 2);
+        assert_eq!(recording.playback()[0].format_type(), "presentation");
+        assert_eq!(
+            recording.playback()[0].processing_time().as_deref(),
+            Some("7177")
+        );
+        assert_eq!(recording.playback()[1].url(), "https://example.com/v/r1");
+        assert_eq!(recording.playback_url(), Some("https://example.com/p/r1"));
+
+        let recording = &response.recordings()[1];
+        assert!(recording.metadata().is_empty());
+        assert!(recording.playback().is_empty());
+        assert_eq!(recording.playback_url(), None);
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "tracing")]
+mod common;
+
+#[cfg(test)]
+mod test {
+    use super::common::{create_response, MockServer};
+    use bigbluebutton::administration::CreateMeetingRequest;
+    use bigbluebutton::Bigbluebutton;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_emits_redacted_span() {
+        let server =
+            MockServer::start(|_| (200, create_response("traced", "duplicateWarning"))).await;
+        let client = Bigbluebutton::new(server.url(), "very-secret-salt");
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mut request = CreateMeetingRequest::new("traced");
+        request.set_moderator_pw(Some("modp".to_string()));
+        client
+            .create_meeting(&request)
+            .await
+            .expect("Unable to parse CreateMeetingResponse");
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let checksum = server.requests()[0]
+            .split("checksum=")
+            .nth(1)
+            .unwrap()
+            .to_string();
+        assert!(output.contains("create_meeting"));
+        assert!(output.contains("api=\"create\""));
+        assert!(output.contains("meeting_id=\"traced\""));
+        assert!(output.contains("http.status=200"));
+        assert!(output.contains("return_code=\"SUCCESS\""));
+        assert!(output.contains("message_key=\"duplicateWarning\""));
+        assert!(output.contains("latency_ms="));
+        assert!(output.contains("checksum=***"));
+        assert!(!output.contains(&checksum));
+        assert!(!output.contains("very-secret-salt"));
+    }
+}